@@ -0,0 +1,154 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+use peg::{error, str::LineCol};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("failed to read input")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Parse(#[from] ParseErrors),
+}
+
+/// A line of puzzle input that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}, column {column}: expected {}: {text:?}", expected_list(.expected))]
+pub struct ParseError {
+    /// 1-based line number within the input.
+    pub line: usize,
+    /// 1-based column of the furthest point the parser reached.
+    pub column: usize,
+    /// Tokens that would have allowed parsing to continue at `column`.
+    pub expected: Vec<String>,
+    /// The offending line.
+    pub text: String,
+}
+
+impl ParseError {
+    /// Wraps a peg error raised while parsing `text` on its own.
+    pub fn from_peg(text: &str, error: error::ParseError<LineCol>) -> Self {
+        ParseError {
+            text: text.to_string(),
+            ..error.into()
+        }
+    }
+
+    /// Prints the error followed by the offending line with a marker under the column.
+    pub fn report(&self) -> String {
+        format!(
+            "line {}, column {}: expected {}\n    {}\n    {:>width$}",
+            self.line,
+            self.column,
+            expected_list(&self.expected),
+            self.text,
+            "^",
+            width = self.column
+        )
+    }
+}
+
+impl From<error::ParseError<LineCol>> for ParseError {
+    fn from(error: error::ParseError<LineCol>) -> Self {
+        let mut expected: Vec<String> = error.expected.tokens().map(String::from).collect();
+        expected.sort();
+
+        ParseError {
+            line: error.location.line,
+            column: error.location.column,
+            expected,
+            text: String::new(),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(_: ParseIntError) -> Self {
+        ParseError {
+            line: 1,
+            column: 1,
+            expected: vec!["a number".to_string()],
+            text: String::new(),
+        }
+    }
+}
+
+fn expected_list(expected: &[String]) -> String {
+    match expected {
+        [] => "<unreported>".to_string(),
+        [token] => token.clone(),
+        tokens => format!("one of {}", tokens.join(", ")),
+    }
+}
+
+/// Every line of an input that failed to parse, reported together.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub struct ParseErrors(pub Vec<ParseError>);
+
+impl Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid line(s)", self.0.len())?;
+
+        for error in &self.0 {
+            write!(f, "\n{}", error.report())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ParseError> for ParseErrors {
+    fn from(error: ParseError) -> Self {
+        ParseErrors(vec![error])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    peg::parser! {
+        grammar test_parser() for str {
+            pub rule switch() -> bool = "on" { true } / "off" { false }
+        }
+    }
+
+    #[test]
+    fn test_from_peg() {
+        let error = ParseError::from_peg("of", test_parser::switch("of").unwrap_err());
+
+        assert_eq!(
+            error,
+            ParseError {
+                line: 1,
+                column: 1,
+                expected: vec!["\"off\"".to_string(), "\"on\"".to_string()],
+                text: "of".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected one of \"off\", \"on\": \"of\""
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let error = ParseError {
+            line: 3,
+            column: 5,
+            expected: vec!["\",\"".to_string()],
+            text: "1,2 3".to_string(),
+        };
+
+        assert_eq!(
+            ParseErrors(vec![error]).to_string(),
+            "1 invalid line(s)\nline 3, column 5: expected \",\"\n    1,2 3\n        ^"
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::Solution;
+
+/// Builds random puzzle inputs of a chosen size, for stress testing a `Solution`.
+///
+/// The same seed and size always give the same input. What `size` counts is up to each
+/// day (lines, characters, cities...), and is documented on its implementation.
+pub trait Generate: Solution {
+    /// A size that gives an input comparable to the real puzzle.
+    const DEFAULT_SIZE: usize;
+
+    fn generate(seed: u64, size: usize) -> String;
+}
+
+/// The random number generator every generator draws from.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
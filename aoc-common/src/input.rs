@@ -0,0 +1,112 @@
+use std::{
+    borrow::Cow,
+    convert::Infallible,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::Result;
+
+/// Where a solution reads its puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Source {
+    /// The input compiled into the solution.
+    #[default]
+    Embedded,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Reads the input, falling back to `embedded` for [`Source::Embedded`].
+    pub fn read(&self, embedded: &'static str) -> Result<Cow<'static, str>> {
+        match self {
+            Source::Embedded => Ok(Cow::Borrowed(embedded)),
+            Source::File(path) => Ok(Cow::Owned(read_input(path)?)),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+        }
+    }
+}
+
+/// `-` selects stdin, anything else is a path to an input file.
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Embedded => write!(f, "embedded input"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String> {
+    Ok(fs::read_to_string(path)?)
+}
+
+/// Iterates over the non-empty lines of `input` together with their 1-based line numbers.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            lines("a\n\nb\r\nc\n").collect::<Vec<_>>(),
+            vec![(1, "a"), (3, "b"), (4, "c")]
+        );
+        assert_eq!(lines("").count(), 0);
+    }
+
+    #[test]
+    fn test_read_input_missing_file() {
+        assert!(read_input("does/not/exist.txt").is_err());
+    }
+
+    #[test]
+    fn test_source_from_str() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!(
+            "inputs/day7.txt".parse(),
+            Ok(Source::File(PathBuf::from("inputs/day7.txt")))
+        );
+    }
+
+    #[test]
+    fn test_source_read() {
+        assert_eq!(Source::Embedded.read("embedded").unwrap(), "embedded");
+
+        let path = std::env::temp_dir().join("aoc-common-test-source-read.txt");
+        fs::write(&path, "from a file").unwrap();
+        assert_eq!(
+            Source::File(path.clone()).read("embedded").unwrap(),
+            "from a file"
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,11 @@
+pub mod error;
+pub mod generate;
+pub mod input;
+pub mod options;
+pub mod parse;
+pub mod solution;
+
+pub use error::{Error, ParseError, ParseErrors, Result};
+pub use generate::Generate;
+pub use options::Options;
+pub use solution::{run, solve, Answer, Part, Solution};
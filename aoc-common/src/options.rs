@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+pub use anyhow::Error;
+
+/// Puzzle constants a solution can be tuned with, by name, from the command line.
+///
+/// Implemented by structs declared with [`options!`](crate::options!), whose defaults
+/// are the values from the puzzle text.
+pub trait Options: Default {
+    /// Every option with its current value, as `name=value`.
+    fn values(&self) -> Vec<String>;
+
+    /// Every option with its default value, as `name=value`.
+    fn defaults() -> Vec<String> {
+        Self::default().values()
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), Error>;
+
+    /// Starts from the defaults and applies `(name, value)` settings in order.
+    fn from_settings(settings: &[(String, String)]) -> Result<Self, Error> {
+        let mut options = Self::default();
+        for (name, value) in settings {
+            options.set(name, value)?;
+        }
+        Ok(options)
+    }
+}
+
+/// For solutions without any constants to tune.
+impl Options for () {
+    fn values(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), Error> {
+        Err(unknown::<()>(name))
+    }
+}
+
+#[doc(hidden)]
+pub fn unknown<O: Options>(name: &str) -> Error {
+    let defaults = O::defaults();
+
+    if defaults.is_empty() {
+        anyhow::anyhow!("Unknown option {:?}, there are none", name)
+    } else {
+        anyhow::anyhow!(
+            "Unknown option {:?}, expected one of {}",
+            name,
+            defaults.join(", ")
+        )
+    }
+}
+
+#[doc(hidden)]
+pub fn invalid(name: &str, value: &str, error: impl Display) -> Error {
+    anyhow::anyhow!("Invalid value {:?} for option {}: {}", value, name, error)
+}
+
+/// Declares a day's options struct, with public fields and the puzzle values as defaults.
+///
+/// ```
+/// aoc_common::options! {
+///     pub struct Options {
+///         /// How long the race lasts.
+///         seconds: i32 = 2503,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! options {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $vis struct $name {
+            $($(#[$field_meta])* pub $field: $ty),*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default),*
+                }
+            }
+        }
+
+        impl $crate::Options for $name {
+            fn values(&self) -> Vec<String> {
+                vec![$(format!("{}={}", stringify!($field), self.$field)),*]
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), $crate::options::Error> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = value
+                            .parse()
+                            .map_err(|e| $crate::options::invalid(name, value, e))?;
+                    })*
+                    _ => return Err($crate::options::unknown::<Self>(name)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::options! {
+        struct Race {
+            seconds: i32 = 2503,
+            reindeer: usize = 9,
+        }
+    }
+
+    fn settings(settings: &[(&str, &str)]) -> Vec<(String, String)> {
+        settings
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_settings() {
+        assert_eq!(Race::from_settings(&[]).unwrap(), Race::default());
+        assert_eq!(
+            Race::from_settings(&settings(&[("seconds", "1000")])).unwrap(),
+            Race {
+                seconds: 1000,
+                reindeer: 9
+            }
+        );
+        assert_eq!(Race::defaults(), vec!["seconds=2503", "reindeer=9"]);
+        assert_eq!(
+            Race::from_settings(&settings(&[("reindeer", "3")]))
+                .unwrap()
+                .values(),
+            vec!["seconds=2503", "reindeer=3"]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = Race::from_settings(&settings(&[("laps", "3")])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown option \"laps\", expected one of seconds=2503, reindeer=9"
+        );
+
+        let error = Race::from_settings(&settings(&[("reindeer", "-1")])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid value \"-1\" for option reindeer: invalid digit found in string"
+        );
+
+        let error = <()>::from_settings(&settings(&[("laps", "3")])).unwrap_err();
+        assert_eq!(error.to_string(), "Unknown option \"laps\", there are none");
+    }
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use crate::{input::lines, ParseError, ParseErrors};
+
+/// Parses a number matched by a peg rule, for use in `{? }` actions:
+///
+/// `rule num() -> u32 = n:$(['0'..='9']+) {? number(n) }`
+pub fn number<T: FromStr>(input: &str) -> Result<T, &'static str> {
+    input.parse().or(Err("number"))
+}
+
+/// Runs `parser` over every non-empty line of `input`.
+///
+/// Every line is attempted, so a malformed input reports all of its bad lines at once.
+pub fn parse_lines<'a, T, E, F>(input: &'a str, parser: F) -> Result<Vec<T>, ParseErrors>
+where
+    E: Into<ParseError>,
+    F: Fn(&'a str) -> Result<T, E>,
+{
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+
+    for (line, text) in lines(input) {
+        match parser(text) {
+            Ok(value) => parsed.push(value),
+            Err(e) => errors.push(ParseError {
+                line,
+                text: text.to_string(),
+                ..e.into()
+            }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(ParseErrors(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u32>("42"), Ok(42));
+        assert_eq!(number::<i32>("-7"), Ok(-7));
+        assert_eq!(number::<u8>("256"), Err("number"));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1\n2\n\n3", str::parse::<u32>),
+            Ok(vec![1, 2, 3])
+        );
+
+        let errors = parse_lines("1\nx\n3\ny", str::parse::<u32>).unwrap_err();
+        assert_eq!(
+            errors
+                .0
+                .iter()
+                .map(|e| (e.line, e.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(2, "x"), (4, "y")]
+        );
+    }
+}
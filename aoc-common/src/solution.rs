@@ -0,0 +1,174 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::{input::Source, Options};
+
+/// A single day's puzzle: the input is parsed once and both parts are answered from it.
+pub trait Solution {
+    const DAY: u8;
+    /// The puzzle input the solution was written against, used unless another one is given.
+    const INPUT: &'static str;
+
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+    /// Puzzle constants the parts can be tuned with; `()` when there are none.
+    type Options: Options;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>>;
+    fn part_one(input: &Self::Input<'_>, options: &Self::Options) -> anyhow::Result<Self::PartOne>;
+    fn part_two(input: &Self::Input<'_>, options: &Self::Options) -> anyhow::Result<Self::PartTwo>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "[Part one]"),
+            Part::Two => write!(f, "[Part two]"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time spent parsing the input, shared by both parts of a day.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:>2} {}: {}", self.day, self.part, self.answer)
+    }
+}
+
+/// Parses `input` and answers the requested part, or both parts when `part` is `None`.
+pub fn solve<S: Solution>(
+    input: &str,
+    part: Option<Part>,
+    options: &S::Options,
+) -> anyhow::Result<Vec<Answer>> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    Part::ALL
+        .iter()
+        .filter(|&&p| part.is_none_or(|part| part == p))
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed, options)?.to_string(),
+                Part::Two => S::part_two(&parsed, options)?.to_string(),
+            };
+            let solve_time = start.elapsed();
+
+            Ok(Answer {
+                day: S::DAY,
+                part,
+                answer,
+                parse_time,
+                solve_time,
+            })
+        })
+        .collect()
+}
+
+/// Entry point for the day binaries: solves both parts and prints the answers.
+///
+/// The input is read from the file named by the first argument, from stdin if that
+/// argument is `-`, and otherwise the embedded input is used.
+pub fn run<S: Solution>() -> anyhow::Result<()> {
+    let source: Source = match env::args().nth(1) {
+        Some(arg) => arg.parse()?,
+        None => Source::Embedded,
+    };
+    let input = source.read(S::INPUT)?;
+
+    for answer in solve::<S>(&input, None, &S::Options::default())? {
+        println!("{}", answer);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "1\n2\n3";
+
+        type Input<'a> = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+        type Options = ();
+
+        fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+            Ok(crate::parse::parse_lines(input, str::parse)?)
+        }
+
+        fn part_one(input: &Vec<u32>, _: &()) -> anyhow::Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(input: &Vec<u32>, _: &()) -> anyhow::Result<u32> {
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = solve::<Sum>(Sum::INPUT, None, &()).unwrap();
+        assert_eq!(
+            answers.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+            vec!["Day  0 [Part one]: 6", "Day  0 [Part two]: 6"]
+        );
+        assert_eq!(answers[0].parse_time, answers[1].parse_time);
+
+        let answers = solve::<Sum>("4\n5", Some(Part::Two), &()).unwrap();
+        assert_eq!(
+            answers
+                .iter()
+                .map(|a| (a.day, a.part, a.answer.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(0, Part::Two, "20")]
+        );
+
+        assert!(solve::<Sum>("4\nfive", None, &()).is_err());
+    }
+}
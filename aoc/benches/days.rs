@@ -0,0 +1,64 @@
+//! Parse and solve times for every day, against its embedded input.
+//!
+//! Criterion keeps baselines under `target/criterion`, so a redesign can be measured with
+//!
+//! ```text
+//! cargo bench -p aoc -- --save-baseline before
+//! # ...change things...
+//! cargo bench -p aoc -- --baseline before
+//! ```
+//!
+//! A single day can be selected with a filter, e.g. `cargo bench -p aoc -- day13/`.
+
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+
+    group.bench_function("parse", |b| {
+        b.iter(|| S::parse(black_box(S::INPUT)).unwrap())
+    });
+
+    let input = S::parse(S::INPUT).unwrap();
+    let options = S::Options::default();
+    group.bench_function("part_one", |b| {
+        b.iter(|| S::part_one(black_box(&input), &options).unwrap())
+    });
+    group.bench_function("part_two", |b| {
+        b.iter(|| S::part_two(black_box(&input), &options).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = days;
+    // Some days take seconds per iteration; `--sample-size` overrides this for quick ones.
+    config = Criterion::default().sample_size(10);
+    targets =
+        bench::<day1::Day1>,
+        bench::<day2::Day2>,
+        bench::<day3::Day3>,
+        bench::<day4::Day4>,
+        bench::<day5::Day5>,
+        bench::<day6::Day6>,
+        bench::<day7::Day7>,
+        bench::<day8::Day8>,
+        bench::<day9::Day9>,
+        bench::<day10::Day10>,
+        bench::<day11::Day11>,
+        bench::<day12::Day12>,
+        bench::<day13::Day13>,
+        bench::<day14::Day14>,
+        bench::<day15::Day15>,
+        bench::<day16::Day16>,
+        bench::<day17::Day17>,
+        bench::<day18::Day18>,
+        bench::<day19::Day19>,
+        bench::<day20::Day20>,
+        bench::<day23::Day23>,
+        bench::<day24::Day24>,
+        bench::<day25::Day25>
+}
+criterion_main!(days);
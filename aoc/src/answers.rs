@@ -0,0 +1,191 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use aoc_common::Answer;
+
+const HEADER: &str = "# day part input-md5 answer";
+
+/// Known good answers, keyed by day, part and a digest of the input they were solved for.
+///
+/// Stored as plain text, one answer per line, so changes show up nicely in diffs.
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<(u8, u8, String), String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: String },
+    New,
+}
+
+/// Identifies an input in the answers file, together with the `name=value` options it was
+/// solved with that differ from the puzzle's.
+pub fn input_key(input: &str, options: &[String]) -> String {
+    let mut context = md5::Context::new();
+    context.consume(input);
+    for option in options {
+        context.consume(format!("\0{}", option));
+    }
+    format!("{:x}", context.compute())
+}
+
+impl Answers {
+    /// Loads the answers file, or starts an empty store if it does not exist yet.
+    pub fn load(path: &Path) -> anyhow::Result<Answers> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        let entries = parse(&contents).with_context(|| format!("Invalid {}", path.display()))?;
+
+        Ok(Answers {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn check(&self, input_key: &str, answer: &Answer) -> Check {
+        match self.entries.get(&key(input_key, answer)) {
+            Some(expected) if *expected == answer.answer => Check::Match,
+            Some(expected) => Check::Mismatch {
+                expected: expected.clone(),
+            },
+            None => Check::New,
+        }
+    }
+
+    pub fn record(&mut self, input_key: &str, answer: &Answer) {
+        self.entries
+            .insert(key(input_key, answer), answer.answer.clone());
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        fs::write(&self.path, self.to_string())
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for ((day, part, input), answer) in &self.entries {
+            writeln!(f, "{} {} {} {}", day, part, input, answer)?;
+        }
+        Ok(())
+    }
+}
+
+fn key(input_key: &str, answer: &Answer) -> (u8, u8, String) {
+    (answer.day, answer.part.number(), input_key.to_string())
+}
+
+fn parse(contents: &str) -> anyhow::Result<BTreeMap<(u8, u8, String), String>> {
+    let mut entries = BTreeMap::new();
+
+    for (line, text) in aoc_common::input::lines(contents) {
+        if text.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = text.splitn(4, ' ').collect();
+        if fields.len() != 4 {
+            bail!("line {}: expected `day part input-md5 answer`", line);
+        }
+
+        let day = fields[0]
+            .parse()
+            .with_context(|| format!("line {}: invalid day", line))?;
+        let part = fields[1]
+            .parse()
+            .with_context(|| format!("line {}: invalid part", line))?;
+
+        entries.insert((day, part, fields[2].to_string()), fields[3].to_string());
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::Part;
+
+    use super::*;
+
+    fn answer(part: Part, answer: &str) -> Answer {
+        Answer {
+            day: 25,
+            part,
+            answer: answer.to_string(),
+            parse_time: Duration::default(),
+            solve_time: Duration::default(),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers {
+            path: PathBuf::new(),
+            entries: BTreeMap::new(),
+        };
+        let key = input_key("To continue, please consult the code grid", &[]);
+
+        assert_eq!(answers.check(&key, &answer(Part::One, "1")), Check::New);
+
+        answers.record(&key, &answer(Part::One, "1"));
+        assert_eq!(answers.check(&key, &answer(Part::One, "1")), Check::Match);
+        assert_eq!(
+            answers.check(&key, &answer(Part::One, "2")),
+            Check::Mismatch {
+                expected: "1".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(&input_key("other input", &[]), &answer(Part::One, "2")),
+            Check::New
+        );
+
+        let options = ["seconds=1000".to_string()];
+        assert_ne!(
+            input_key("To continue, please consult the code grid", &options),
+            key
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers {
+            path: PathBuf::new(),
+            entries: BTreeMap::new(),
+        };
+        answers.record("abc", &answer(Part::Two, "Merry Christmas!"));
+        answers.record("abc", &answer(Part::One, "19980801"));
+
+        let text = answers.to_string();
+        assert_eq!(
+            text,
+            "# day part input-md5 answer\n25 1 abc 19980801\n25 2 abc Merry Christmas!\n"
+        );
+        assert_eq!(parse(&text).unwrap(), answers.entries);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("25 1 abc").is_err());
+        assert!(parse("x 1 abc 1").is_err());
+    }
+}
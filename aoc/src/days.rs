@@ -0,0 +1,167 @@
+use aoc_common::{solve, Answer, Generate, Options, Part, Solution};
+
+type Solver = fn(&str, Option<Part>, &[(String, String)]) -> anyhow::Result<Vec<Answer>>;
+type Generator = fn(u64, usize) -> String;
+type Resolver = fn(&[(String, String)]) -> anyhow::Result<Vec<String>>;
+
+/// A solution registered with the runner, with its generic `Solution` impl erased.
+pub struct Day {
+    pub day: u8,
+    pub input: &'static str,
+    pub default_size: usize,
+    solver: Solver,
+    generator: Generator,
+    resolver: Resolver,
+}
+
+impl Day {
+    const fn new<S: Generate>() -> Day {
+        Day {
+            day: S::DAY,
+            input: S::INPUT,
+            default_size: S::DEFAULT_SIZE,
+            solver: solve_with::<S>,
+            generator: S::generate,
+            resolver: changed_options::<S>,
+        }
+    }
+
+    /// Solves `input` with the day's options changed by `(name, value)` settings.
+    pub fn solve(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        settings: &[(String, String)],
+    ) -> anyhow::Result<Vec<Answer>> {
+        (self.solver)(input, part, settings)
+    }
+
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generator)(seed, size)
+    }
+
+    /// The options `(name, value)` settings leave different from the puzzle's, sorted, as
+    /// `name=value`.
+    pub fn changed_options(&self, settings: &[(String, String)]) -> anyhow::Result<Vec<String>> {
+        (self.resolver)(settings)
+    }
+}
+
+fn solve_with<S: Solution>(
+    input: &str,
+    part: Option<Part>,
+    settings: &[(String, String)],
+) -> anyhow::Result<Vec<Answer>> {
+    let options = S::Options::from_settings(settings)?;
+    solve::<S>(input, part, &options)
+}
+
+fn changed_options<S: Solution>(settings: &[(String, String)]) -> anyhow::Result<Vec<String>> {
+    let defaults = S::Options::defaults();
+    let mut changed: Vec<String> = S::Options::from_settings(settings)?
+        .values()
+        .into_iter()
+        .filter(|value| !defaults.contains(value))
+        .collect();
+    changed.sort();
+    Ok(changed)
+}
+
+pub const DAYS: [Day; 23] = [
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day23::Day23>(),
+    Day::new::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        assert!(DAYS.windows(2).all(|pair| pair[0].day < pair[1].day));
+    }
+
+    /// Small generated inputs must be accepted and solved by every day.
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in &DAYS {
+            let size = match day.day {
+                9 | 13 => 5,
+                17 => 12,
+                20 => 10_000,
+                24 => 24,
+                25 => 100,
+                _ => day.default_size.min(20),
+            };
+
+            for seed in 0..2 {
+                let input = day.generate(seed, size);
+                assert_eq!(input, day.generate(seed, size));
+
+                let answers = day.solve(&input, None, &[]);
+                assert!(
+                    answers.is_ok(),
+                    "day {} seed {}: {:?}",
+                    day.day,
+                    seed,
+                    answers
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_changed_options() {
+        let settings = |settings: &[(&str, &str)]| -> Vec<(String, String)> {
+            settings
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect()
+        };
+        let day = find(14).unwrap();
+
+        assert!(day.changed_options(&[]).unwrap().is_empty());
+        assert!(day
+            .changed_options(&settings(&[("seconds", "2503")]))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            day.changed_options(&settings(&[("seconds", "1000"), ("seconds", "5")]))
+                .unwrap(),
+            vec!["seconds=5"]
+        );
+        assert!(day.changed_options(&settings(&[("laps", "3")])).is_err());
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).map(|d| d.day), Some(7));
+        assert!(find(21).is_none());
+        assert!(find(26).is_none());
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context};
+use aoc_common::{input::Source, Answer, Part};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use rayon::prelude::*;
+
+mod answers;
+mod days;
+mod output;
+
+use answers::{Answers, Check};
+use days::Day;
+use output::Format;
+
+/// Advent of Code 2015 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Print a random puzzle input for a day
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct RunArgs {
+    /// Day to solve
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Solve every day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Only solve this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file for the selected day, or `-` to read it from stdin
+    #[arg(short, long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<Source>,
+
+    /// Directory of `dayN.txt` input files; days without a file use their embedded input
+    #[arg(long, value_name = "DIR", conflicts_with = "input")]
+    input_dir: Option<PathBuf>,
+
+    /// Output format; json and csv include parse and solve times in microseconds
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// File of known good answers to check against, keyed by day, part and input
+    #[arg(long, value_name = "PATH", default_value = "answers.txt")]
+    answers: PathBuf,
+
+    /// Save the answers of this run as the known good ones
+    #[arg(long)]
+    record: bool,
+
+    /// Change one of the day's puzzle constants; repeat for several
+    #[arg(
+        long = "set",
+        value_name = "NAME=VALUE",
+        value_parser = parse_setting,
+        conflicts_with = "all"
+    )]
+    settings: Vec<(String, String)>,
+
+    /// Number of days to solve at once [default: number of CPUs]
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Seed for the random number generator; the same seed gives the same input
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// Size of the input, as described by the day's generator [default: about the real input's]
+    #[arg(long)]
+    size: Option<usize>,
+}
+
+impl RunArgs {
+    fn source(&self, day: &Day) -> Source {
+        if let Some(source) = &self.input {
+            return source.clone();
+        }
+
+        match &self.input_dir {
+            Some(dir) => {
+                let path = dir.join(format!("day{}.txt", day.day));
+                if path.is_file() {
+                    Source::File(path)
+                } else {
+                    Source::Embedded
+                }
+            }
+            None => Source::Embedded,
+        }
+    }
+}
+
+fn parse_setting(setting: &str) -> Result<(String, String), String> {
+    match setting.split_once('=') {
+        Some((name, value)) => Ok((name.trim().to_string(), value.trim().to_string())),
+        None => Err(format!("expected NAME=VALUE, got {:?}", setting)),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Generate(args) => generate(args),
+    }
+}
+
+fn generate(args: GenerateArgs) -> anyhow::Result<()> {
+    let day = days::find(args.day).with_context(|| format!("No solution for day {}", args.day))?;
+
+    print!(
+        "{}",
+        day.generate(args.seed, args.size.unwrap_or(day.default_size))
+    );
+
+    Ok(())
+}
+
+/// Stack size for the solver threads; day 6 keeps a million lights on the stack.
+const STACK_SIZE: usize = 16 * 1024 * 1024;
+
+/// What solving one day produced: the answers with the key of the input they were
+/// solved for, and how long it took.
+struct Solved {
+    answers: anyhow::Result<(String, Vec<Answer>)>,
+    time: Duration,
+}
+
+fn solve_day(args: &RunArgs, day: &Day, part: Option<Part>) -> Solved {
+    let start = Instant::now();
+    let source = args.source(day);
+    // A panicking day fails on its own rather than taking the whole pool down with it.
+    let answers = panic::catch_unwind(AssertUnwindSafe(|| {
+        source
+            .read(day.input)
+            .with_context(|| format!("Failed to read {}", source))
+            .and_then(|input| {
+                let answers = day.solve(&input, part, &args.settings)?;
+                let options = day.changed_options(&args.settings)?;
+                Ok((answers::input_key(&input, &options), answers))
+            })
+    }))
+    .unwrap_or_else(|payload| Err(anyhow!("Panicked: {}", panic_message(&*payload))));
+
+    Solved {
+        answers,
+        time: start.elapsed(),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+fn run(args: RunArgs) -> anyhow::Result<()> {
+    let part = args.part.and_then(Part::from_number);
+
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![days::find(day).with_context(|| format!("No solution for day {}", day))?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let format = args.format;
+    let mut collected = Vec::new();
+    let mut failures = 0;
+    let mut mismatches = 0;
+
+    // Without an answers file there is nothing to check against, so only use one
+    // when it exists or is about to be written.
+    let mut known = if args.record || args.answers.exists() {
+        Some(Answers::load(&args.answers)?)
+    } else {
+        None
+    };
+
+    if let Some(header) = format.header() {
+        println!("{}", header);
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.jobs.map_or(0, usize::from))
+        .stack_size(STACK_SIZE)
+        .build()?;
+
+    let start = Instant::now();
+    let solved: Vec<Solved> = pool.install(|| {
+        days.par_iter()
+            .map(|day| solve_day(&args, day, part))
+            .collect()
+    });
+    let wall_clock = start.elapsed();
+
+    let timings: Vec<(u8, Duration)> = days
+        .iter()
+        .zip(&solved)
+        .map(|(day, solved)| (day.day, solved.time))
+        .collect();
+
+    for (day, solved) in days.iter().zip(solved) {
+        let answers = solved.answers.map(|(input_key, answers)| {
+            if let Some(known) = &mut known {
+                for answer in &answers {
+                    if args.record {
+                        known.record(&input_key, answer);
+                        continue;
+                    }
+
+                    match known.check(&input_key, answer) {
+                        Check::Match => {}
+                        Check::Mismatch { expected } => {
+                            eprintln!("{}: expected {}", answer, expected);
+                            mismatches += 1;
+                        }
+                        Check::New => eprintln!("{}: new answer, not yet recorded", answer),
+                    }
+                }
+            }
+            answers
+        });
+
+        match answers {
+            Ok(answers) if format.is_streaming() => print!("{}", format.render(&answers)),
+            Ok(answers) => collected.extend(answers),
+            Err(e) => {
+                eprintln!("Day {:>2}: {:#}", day.day, e);
+                failures += 1;
+            }
+        }
+    }
+
+    if !format.is_streaming() {
+        print!("{}", format.render(&collected));
+    }
+
+    // JSON and CSV already carry their own timings.
+    if format == Format::Text && days.len() > 1 {
+        println!();
+        print!("{}", output::timing_table(&timings, wall_clock));
+    }
+
+    if let Some(known) = known.filter(|_| args.record) {
+        known.save()?;
+        eprintln!("Recorded answers to {}", known.path().display());
+    }
+
+    if failures > 0 {
+        bail!("{} day(s) failed", failures);
+    }
+
+    if mismatches > 0 {
+        bail!(
+            "{} answer(s) differ from {}",
+            mismatches,
+            args.answers.display()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use aoc_common::Answer;
+use clap::ValueEnum;
+use json::{object, JsonValue};
+
+/// How the runner prints its answers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// One human readable line per answer
+    #[default]
+    Text,
+    /// A JSON array with one object per answer, including timings
+    Json,
+    /// A CSV table with one row per answer, including timings
+    Csv,
+}
+
+impl Format {
+    /// Whether each day's answers can be printed on their own, in day order, rather than
+    /// only together in a single document once every day is solved.
+    pub fn is_streaming(self) -> bool {
+        self != Format::Json
+    }
+
+    /// Printed once before the first answer.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some("day,part,answer,parse_us,solve_us"),
+            _ => None,
+        }
+    }
+
+    pub fn render(self, answers: &[Answer]) -> String {
+        match self {
+            Format::Text => answers.iter().map(|a| format!("{}\n", a)).collect(),
+            Format::Csv => answers
+                .iter()
+                .map(|a| format!("{}\n", csv_row(a)))
+                .collect(),
+            Format::Json => {
+                let array = JsonValue::Array(answers.iter().map(json_object).collect());
+                format!("{}\n", array.pretty(2))
+            }
+        }
+    }
+}
+
+/// Width of the bar drawn for the slowest day.
+const BAR_WIDTH: usize = 40;
+
+/// How long each day took, slowest first, with bars relative to the slowest day.
+pub fn timing_table(timings: &[(u8, Duration)], wall_clock: Duration) -> String {
+    let mut timings = timings.to_vec();
+    timings.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let total: Duration = timings.iter().map(|&(_, time)| time).sum();
+    let slowest = timings.first().map_or(Duration::ZERO, |&(_, time)| time);
+
+    let mut table = String::from("Day        Time  Share\n");
+    for &(day, time) in &timings {
+        let share = ratio(time, total);
+        let bar = (ratio(time, slowest) * BAR_WIDTH as f64).round() as usize;
+
+        let row = format!(
+            "{:>3} {:>11} {:>5.1}%  {}",
+            day,
+            format!("{:.3?}", time),
+            share * 100.0,
+            "#".repeat(bar)
+        );
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+    table.push_str(&format!(
+        "{} day(s) in {:.3?}, {:.3?} of work in total\n",
+        timings.len(),
+        wall_clock,
+        total
+    ));
+
+    table
+}
+
+fn ratio(part: Duration, whole: Duration) -> f64 {
+    if whole.is_zero() {
+        0.0
+    } else {
+        part.as_secs_f64() / whole.as_secs_f64()
+    }
+}
+
+fn json_object(answer: &Answer) -> JsonValue {
+    object! {
+        day: answer.day,
+        part: answer.part.number(),
+        answer: answer.answer.as_str(),
+        parse_us: answer.parse_time.as_micros() as u64,
+        solve_us: answer.solve_time.as_micros() as u64,
+    }
+}
+
+fn csv_row(answer: &Answer) -> String {
+    format!(
+        "{},{},{},{},{}",
+        answer.day,
+        answer.part.number(),
+        csv_field(&answer.answer),
+        answer.parse_time.as_micros(),
+        answer.solve_time.as_micros()
+    )
+}
+
+/// Quotes a field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::Part;
+
+    use super::*;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer {
+                day: 25,
+                part: Part::One,
+                answer: "19980801".to_string(),
+                parse_time: Duration::from_micros(3),
+                solve_time: Duration::from_micros(1500),
+            },
+            Answer {
+                day: 25,
+                part: Part::Two,
+                answer: "Merry \"Christmas\", all!".to_string(),
+                parse_time: Duration::from_micros(3),
+                solve_time: Duration::from_nanos(200),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            Format::Csv.render(&answers()),
+            "25,1,19980801,3,1500\n25,2,\"Merry \"\"Christmas\"\", all!\",3,0\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let parsed = json::parse(&Format::Json.render(&answers())).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0]["day"], 25);
+        assert_eq!(parsed[0]["part"], 1);
+        assert_eq!(parsed[0]["answer"], "19980801");
+        assert_eq!(parsed[0]["solve_us"], 1500);
+        assert_eq!(parsed[1]["answer"], "Merry \"Christmas\", all!");
+    }
+
+    #[test]
+    fn test_timing_table() {
+        let timings = [
+            (1, Duration::from_millis(1)),
+            (4, Duration::from_millis(300)),
+            (20, Duration::from_millis(99)),
+        ];
+
+        assert_eq!(
+            timing_table(&timings, Duration::from_millis(310)),
+            "Day        Time  Share\n\
+             \x20 4   300.000ms  75.0%  ########################################\n\
+             \x2020    99.000ms  24.8%  #############\n\
+             \x20 1     1.000ms   0.2%\n\
+             3 day(s) in 310.000ms, 400.000ms of work in total\n"
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            Format::Text.render(&answers()[..1]),
+            "Day 25 [Part one]: 19980801\n"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Floor tracking over 100 MB of instructions, against a plain character by character fold.
+//!
+//! ```text
+//! cargo bench -p day1 --bench floors
+//! ```
+
+use aoc_common::Generate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day1::{calculate_floor, find_basement, Day1};
+
+const SIZE: usize = 100_000_000;
+
+fn fold_floor(input: &str) -> i64 {
+    input.chars().fold(0, |floor, c| match c {
+        '(' => floor + 1,
+        ')' => floor - 1,
+        _ => panic!("Unexpected character"),
+    })
+}
+
+fn fold_basement(input: &str) -> Option<usize> {
+    let mut floor = 0;
+    for (i, c) in input.chars().enumerate() {
+        floor += match c {
+            '(' => 1,
+            ')' => -1,
+            _ => panic!("Unexpected character"),
+        };
+        if floor == -1 {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+fn floors(c: &mut Criterion) {
+    let input = Day1::generate(0, SIZE);
+
+    let mut group = c.benchmark_group("floor");
+    group
+        .sample_size(10)
+        .throughput(Throughput::Bytes(SIZE as u64));
+    group.bench_function("fold", |b| b.iter(|| fold_floor(black_box(&input))));
+    group.bench_function("blocks", |b| {
+        b.iter(|| calculate_floor(black_box(&input)).unwrap())
+    });
+    group.finish();
+}
+
+fn basement(c: &mut Criterion) {
+    // Santa hovers just above the ground and only enters the basement at the very end,
+    // so no block can be skipped on its floor alone.
+    let input = "()".repeat(SIZE / 2 - 1) + "))";
+
+    let mut group = c.benchmark_group("basement");
+    group
+        .sample_size(10)
+        .throughput(Throughput::Bytes(SIZE as u64));
+    group.bench_function("fold", |b| b.iter(|| fold_basement(black_box(&input))));
+    group.bench_function("blocks", |b| {
+        b.iter(|| find_basement(black_box(&input)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, floors, basement);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day1;
+
+impl Generate for Day1 {
+    const DEFAULT_SIZE: usize = 7000;
+
+    /// `size` is the number of parentheses. Santa always reaches the basement at some point.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let mut floor: i64 = 0;
+        let mut basement = false;
+
+        (0..size)
+            .map(|i| {
+                let remaining = (size - i) as i64;
+                let down = (!basement && remaining <= floor + 2) || rng.gen_bool(0.5);
+
+                floor += if down { -1 } else { 1 };
+                basement |= floor == -1;
+
+                if down {
+                    ')'
+                } else {
+                    '('
+                }
+            })
+            .collect()
+    }
+}
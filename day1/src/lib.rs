@@ -0,0 +1,622 @@
+use std::{
+    collections::VecDeque,
+    convert::{TryFrom, TryInto},
+    io::{self, Read},
+};
+
+use anyhow::Context;
+use aoc_common::Solution;
+use thiserror::Error;
+
+pub mod generate;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = &'a str;
+    type PartOne = i64;
+    type PartTwo = u64;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<&str> {
+        Ok(input)
+    }
+
+    fn part_one(input: &&str, _: &()) -> anyhow::Result<i64> {
+        Ok(calculate_floor(input)?)
+    }
+
+    fn part_two(input: &&str, _: &()) -> anyhow::Result<u64> {
+        find_basement(input)?.context("Santa never enters the basement")
+    }
+}
+
+/// Size of the chunks pulled from readers of instructions.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Size of the blocks [`FloorTracker::feed`] tries to count in one go.
+const BLOCK_SIZE: usize = 64;
+
+const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+const LOW_SEVEN_BITS: u64 = u64::from_ne_bytes([0x7f; 8]);
+
+/// Why a stream of instructions could not be followed.
+#[derive(Debug, Error)]
+pub enum FloorError {
+    #[error("unexpected {:?} at byte offset {offset}", char::from(*.byte))]
+    InvalidInstruction { byte: u8, offset: u64 },
+    #[error("failed to read instructions")]
+    Io(#[from] io::Error),
+}
+
+/// Follows Santa through the building one chunk of instructions at a time, so streams of
+/// any length are tracked in constant memory.
+///
+/// Whitespace is skipped and does not count as an instruction position.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FloorTracker {
+    floor: i64,
+    instructions: u64,
+    bytes: u64,
+    basement: Option<u64>,
+}
+
+impl FloorTracker {
+    pub fn new() -> FloorTracker {
+        FloorTracker::default()
+    }
+
+    /// Follows the instructions in `chunk`, which continues where the previous one ended.
+    ///
+    /// Blocks of nothing but parentheses are counted a word at a time; only blocks with
+    /// whitespace, invalid bytes or the first basement entry in them are walked byte by byte.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), FloorError> {
+        let mut blocks = chunk.chunks_exact(BLOCK_SIZE);
+        for (i, block) in (&mut blocks).enumerate() {
+            if !self.feed_block(block) {
+                self.feed_bytes(block, (i * BLOCK_SIZE) as u64)?;
+            }
+        }
+        self.feed_bytes(
+            blocks.remainder(),
+            (chunk.len() - blocks.remainder().len()) as u64,
+        )?;
+
+        self.bytes += chunk.len() as u64;
+        Ok(())
+    }
+
+    /// Fast path for a whole block, returning `false` if it needs to be walked byte by byte.
+    fn feed_block(&mut self, block: &[u8]) -> bool {
+        let (opens, closes) = count_parentheses(block);
+        if opens + closes != block.len() {
+            return false;
+        }
+
+        // Santa cannot drop more floors than there are instructions, so the prefix minimum
+        // only matters close to the ground.
+        let may_enter_basement = self.basement.is_none() && self.floor < BLOCK_SIZE as i64;
+        if may_enter_basement && self.floor + lowest_prefix(block) < 0 {
+            return false;
+        }
+
+        self.floor += opens as i64 - closes as i64;
+        self.instructions += block.len() as u64;
+        true
+    }
+
+    /// Walks `bytes` one at a time; `offset` is where they start within the current chunk.
+    fn feed_bytes(&mut self, bytes: &[u8], offset: u64) -> Result<(), FloorError> {
+        for (i, &byte) in bytes.iter().enumerate() {
+            self.floor += match step(byte, self.bytes + offset + i as u64)? {
+                Some(step) => step,
+                None => continue,
+            };
+            self.instructions += 1;
+
+            if self.floor == -1 && self.basement.is_none() {
+                self.basement = Some(self.instructions);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Follows every instruction `reader` has left.
+    pub fn read<R: Read>(&mut self, reader: R) -> Result<(), FloorError> {
+        read_chunks(reader, |chunk| self.feed(chunk))
+    }
+
+    /// The floor Santa is on after the instructions so far.
+    pub fn floor(&self) -> i64 {
+        self.floor
+    }
+
+    /// 1-based position of the instruction that first took Santa into the basement.
+    pub fn basement(&self) -> Option<u64> {
+        self.basement
+    }
+
+    /// Instructions followed so far, not counting whitespace.
+    pub fn instructions(&self) -> u64 {
+        self.instructions
+    }
+}
+
+/// How many floors an instruction moves Santa, or `None` for whitespace to skip.
+fn step(byte: u8, offset: u64) -> Result<Option<i64>, FloorError> {
+    match byte {
+        b'(' => Ok(Some(1)),
+        b')' => Ok(Some(-1)),
+        byte if byte.is_ascii_whitespace() => Ok(None),
+        byte => Err(FloorError::InvalidInstruction { byte, offset }),
+    }
+}
+
+/// Counts the `(` and `)` bytes in `bytes`, eight at a time.
+pub fn count_parentheses(bytes: &[u8]) -> (usize, usize) {
+    let mut words = bytes.chunks_exact(8);
+    let (mut opens, mut closes) = (0, 0);
+
+    for word in &mut words {
+        let word = u64::from_ne_bytes(word.try_into().unwrap());
+        opens += count_bytes(word, b'(');
+        closes += count_bytes(word, b')');
+    }
+    for &byte in words.remainder() {
+        opens += (byte == b'(') as usize;
+        closes += (byte == b')') as usize;
+    }
+
+    (opens, closes)
+}
+
+/// Counts the bytes of `word` equal to `byte`.
+fn count_bytes(word: u64, byte: u8) -> usize {
+    let zeroes = word ^ (ONES * byte as u64);
+    // Sets the high bit of exactly the zero bytes, without carries between bytes.
+    let high_bits = !(((zeroes & LOW_SEVEN_BITS) + LOW_SEVEN_BITS) | zeroes | LOW_SEVEN_BITS);
+    high_bits.count_ones() as usize
+}
+
+/// The lowest floor, relative to the start, reached part way through a block of parentheses.
+///
+/// Works eight instructions at a time: `(` and `)` differ only in their lowest bit, so a
+/// word of instructions packs into a byte with one bit set per `)`, which indexes a table
+/// of the lowest prefix of every combination.
+fn lowest_prefix(block: &[u8]) -> i64 {
+    let mut words = block.chunks_exact(8);
+    let mut floor = 0;
+    let mut lowest = 0;
+
+    for word in &mut words {
+        let downs = pack_low_bits(u64::from_le_bytes(word.try_into().unwrap()));
+        lowest = lowest.min(floor + LOWEST_PREFIXES[downs as usize] as i64);
+        floor += 8 - 2 * downs.count_ones() as i64;
+    }
+    for &byte in words.remainder() {
+        floor += 1 - 2 * (byte & 1) as i64;
+        lowest = lowest.min(floor);
+    }
+
+    lowest
+}
+
+/// Gathers the lowest bit of each byte of a little endian word, first byte lowest.
+fn pack_low_bits(word: u64) -> u8 {
+    ((word & ONES).wrapping_mul(0x0102_0408_1020_4080) >> 56) as u8
+}
+
+/// For eight instructions with a bit set per `)`, the lowest floor reached relative to the start.
+const LOWEST_PREFIXES: [i8; 256] = lowest_prefixes();
+
+const fn lowest_prefixes() -> [i8; 256] {
+    let mut table = [0; 256];
+    let mut downs = 0;
+    while downs < 256 {
+        let (mut floor, mut lowest, mut bit) = (0, 0, 0);
+        while bit < 8 {
+            floor += if downs & (1 << bit) != 0 { -1 } else { 1 };
+            if floor < lowest {
+                lowest = floor;
+            }
+            bit += 1;
+        }
+        table[downs] = lowest;
+        downs += 1;
+    }
+    table
+}
+
+fn read_chunks<R: Read>(
+    mut reader: R,
+    mut feed: impl FnMut(&[u8]) -> Result<(), FloorError>,
+) -> Result<(), FloorError> {
+    let mut buffer = vec![0; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => feed(&buffer[..read])?,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Follows all instructions from `reader`.
+pub fn track_floors<R: Read>(reader: R) -> Result<FloorTracker, FloorError> {
+    let mut tracker = FloorTracker::new();
+    tracker.read(reader)?;
+    Ok(tracker)
+}
+
+/// When Santa first reached a floor, and how long he spent there.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FloorVisits {
+    /// Position of the instruction that first took Santa to the floor; 0 for the ground
+    /// floor he starts on.
+    pub first_reached: u64,
+    /// How many positions, counting the start, left Santa on the floor.
+    pub steps: u64,
+}
+
+/// Everything about Santa's trip through the building, gathered in one pass.
+///
+/// Like [`FloorTracker`], it can be fed a stream in chunks. Its memory grows with the
+/// number of floors visited and basement entries, not with the length of the stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FloorTrace {
+    tracker: FloorTracker,
+    /// The lowest floor visited, which `visits` starts at.
+    lowest: i64,
+    visits: VecDeque<FloorVisits>,
+    basement_entries: Vec<u64>,
+}
+
+impl Default for FloorTrace {
+    fn default() -> Self {
+        FloorTrace {
+            tracker: FloorTracker::default(),
+            lowest: 0,
+            visits: VecDeque::from(vec![FloorVisits {
+                first_reached: 0,
+                steps: 1,
+            }]),
+            basement_entries: Vec::new(),
+        }
+    }
+}
+
+impl FloorTrace {
+    pub fn new() -> FloorTrace {
+        FloorTrace::default()
+    }
+
+    /// Follows the instructions in `chunk`, which continues where the previous one ended.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), FloorError> {
+        let tracker = &mut self.tracker;
+        for (i, &byte) in chunk.iter().enumerate() {
+            let step = match step(byte, tracker.bytes + i as u64)? {
+                Some(step) => step,
+                None => continue,
+            };
+            tracker.floor += step;
+            tracker.instructions += 1;
+            let position = tracker.instructions;
+
+            if tracker.floor == -1 && step == -1 {
+                tracker.basement.get_or_insert(position);
+                self.basement_entries.push(position);
+            }
+
+            // Floors change one at a time, so a new floor is always just past either end.
+            let new = FloorVisits {
+                first_reached: position,
+                steps: 0,
+            };
+            if tracker.floor < self.lowest {
+                self.lowest = tracker.floor;
+                self.visits.push_front(new);
+            } else if tracker.floor >= self.lowest + self.visits.len() as i64 {
+                self.visits.push_back(new);
+            }
+            self.visits[(tracker.floor - self.lowest) as usize].steps += 1;
+        }
+
+        tracker.bytes += chunk.len() as u64;
+        Ok(())
+    }
+
+    /// Follows every instruction `reader` has left.
+    pub fn read<R: Read>(&mut self, reader: R) -> Result<(), FloorError> {
+        read_chunks(reader, |chunk| self.feed(chunk))
+    }
+
+    /// The final floor, first basement entry and instruction count.
+    pub fn tracker(&self) -> &FloorTracker {
+        &self.tracker
+    }
+
+    pub fn min_floor(&self) -> i64 {
+        self.lowest
+    }
+
+    pub fn max_floor(&self) -> i64 {
+        self.lowest + self.visits.len() as i64 - 1
+    }
+
+    /// Position of the instruction that first took Santa to `floor`, if he ever got there.
+    pub fn first_reached(&self, floor: i64) -> Option<u64> {
+        self.visits(floor).map(|visits| visits.first_reached)
+    }
+
+    /// How many positions, counting the start, left Santa on `floor`.
+    pub fn steps_on(&self, floor: i64) -> u64 {
+        self.visits(floor).map_or(0, |visits| visits.steps)
+    }
+
+    pub fn visits(&self, floor: i64) -> Option<FloorVisits> {
+        let index = usize::try_from(floor.checked_sub(self.lowest)?).ok()?;
+        self.visits.get(index).copied()
+    }
+
+    /// Every floor visited, from the lowest up.
+    pub fn floors(&self) -> impl Iterator<Item = (i64, FloorVisits)> + '_ {
+        (self.lowest..).zip(self.visits.iter().copied())
+    }
+
+    /// Positions of every instruction that took Santa from the ground floor into the basement.
+    pub fn basement_entries(&self) -> &[u64] {
+        &self.basement_entries
+    }
+}
+
+/// Traces all instructions from `reader`.
+pub fn trace_floors<R: Read>(reader: R) -> Result<FloorTrace, FloorError> {
+    let mut trace = FloorTrace::new();
+    trace.read(reader)?;
+    Ok(trace)
+}
+
+pub fn calculate_floor(input: &str) -> Result<i64, FloorError> {
+    Ok(track_floors(input.as_bytes())?.floor())
+}
+
+pub fn find_basement(input: &str) -> Result<Option<u64>, FloorError> {
+    Ok(track_floors(input.as_bytes())?.basement())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_calculate_floor() {
+        assert_eq!(calculate_floor("(())").unwrap(), 0);
+        assert_eq!(calculate_floor("(((").unwrap(), 3);
+        assert_eq!(calculate_floor("(()(()(").unwrap(), 3);
+        assert_eq!(calculate_floor("())").unwrap(), -1);
+        assert_eq!(calculate_floor("))(").unwrap(), -1);
+        assert_eq!(calculate_floor(")))").unwrap(), -3);
+        assert_eq!(calculate_floor(")())())").unwrap(), -3);
+    }
+    #[test]
+    fn test_find_basement() {
+        assert_eq!(find_basement(")").unwrap(), Some(1));
+        assert_eq!(find_basement("()())").unwrap(), Some(5));
+        assert_eq!(find_basement("(()").unwrap(), None);
+    }
+
+    #[test]
+    fn test_count_parentheses() {
+        assert_eq!(count_parentheses(b""), (0, 0));
+        assert_eq!(count_parentheses(b"(()\n)x(((((()"), (8, 3));
+        assert_eq!(count_parentheses(&[b'(' ^ 0x80; 16]), (0, 0));
+    }
+
+    #[test]
+    fn test_lowest_prefix() {
+        for downs in 0..=255u8 {
+            let word: Vec<u8> = (0..8)
+                .map(|bit| if downs & (1 << bit) != 0 { b')' } else { b'(' })
+                .collect();
+            assert_eq!(
+                pack_low_bits(u64::from_le_bytes(word[..].try_into().unwrap())),
+                downs
+            );
+        }
+
+        assert_eq!(lowest_prefix(b""), 0);
+        assert_eq!(lowest_prefix(b"(((((((())))))))))"), -2);
+        assert_eq!(lowest_prefix(b"()))((((((((((((((((((()"), -2);
+        assert_eq!(lowest_prefix(&[b')'; 64]), -64);
+    }
+
+    #[test]
+    fn test_blocks() {
+        // Whole blocks on the way up, the basement entered mid block, and whitespace.
+        let mut input = "(".repeat(100) + &")".repeat(150) + " ";
+        input += &"()".repeat(64);
+        let tracker = track_floors(input.as_bytes()).unwrap();
+
+        assert_eq!(tracker.floor(), -50);
+        assert_eq!(tracker.basement(), Some(201));
+        assert_eq!(tracker.instructions(), 378);
+    }
+
+    #[test]
+    fn test_whitespace_is_skipped() {
+        let tracker = track_floors("()\n ())\r\n".as_bytes()).unwrap();
+        assert_eq!(tracker.floor(), -1);
+        assert_eq!(tracker.basement(), Some(5));
+        assert_eq!(tracker.instructions(), 5);
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        let mut tracker = FloorTracker::new();
+        tracker.feed(b"(()").unwrap();
+        let error = tracker.feed(b"\n)x(").unwrap_err();
+
+        assert!(matches!(
+            error,
+            FloorError::InvalidInstruction {
+                byte: b'x',
+                offset: 5
+            }
+        ));
+        assert_eq!(error.to_string(), "unexpected 'x' at byte offset 5");
+    }
+
+    #[test]
+    fn test_read_error() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        assert!(matches!(track_floors(Failing), Err(FloorError::Io(_))));
+    }
+
+    #[test]
+    fn test_trace() {
+        let trace = trace_floors("()())(\n)))((".as_bytes()).unwrap();
+
+        assert_eq!(trace.tracker().floor(), -1);
+        assert_eq!(trace.tracker().basement(), Some(5));
+        assert_eq!((trace.min_floor(), trace.max_floor()), (-3, 1));
+        assert_eq!(trace.basement_entries(), &[5, 7]);
+        assert_eq!(
+            trace
+                .floors()
+                .map(|(floor, visits)| (floor, visits.first_reached, visits.steps))
+                .collect::<Vec<_>>(),
+            vec![(-3, 9, 1), (-2, 8, 2), (-1, 5, 3), (0, 0, 4), (1, 1, 2)]
+        );
+        assert_eq!(trace.first_reached(-2), Some(8));
+        assert_eq!(trace.first_reached(2), None);
+        assert_eq!(trace.steps_on(0), 4);
+        assert_eq!(trace.steps_on(-4), 0);
+    }
+
+    #[test]
+    fn test_empty_trace() {
+        let trace = FloorTrace::new();
+
+        assert_eq!((trace.min_floor(), trace.max_floor()), (0, 0));
+        assert_eq!(trace.steps_on(0), 1);
+        assert_eq!(trace.first_reached(0), Some(0));
+        assert!(trace.basement_entries().is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_floor_counts_parentheses(input in "[()]{0,200}") {
+            let up = input.matches('(').count() as i64;
+            let down = input.matches(')').count() as i64;
+
+            prop_assert_eq!(calculate_floor(&input).unwrap(), up - down);
+        }
+
+        #[test]
+        fn prop_basement_is_first_prefix_below_ground(input in "[()]{0,200}") {
+            let floor = |end: usize| calculate_floor(&input[..end]).unwrap();
+
+            match find_basement(&input).unwrap() {
+                Some(position) => {
+                    let position = position as usize;
+                    prop_assert_eq!(floor(position), -1);
+                    prop_assert!((0..position).all(|i| floor(i) >= 0));
+                }
+                None => {
+                    prop_assert!((0..=input.len()).all(|i| floor(i) >= 0));
+                }
+            }
+        }
+
+        #[test]
+        fn prop_blocks_track_like_bytes(input in "[()]{0,600}") {
+            let mut bytes = FloorTracker::new();
+            bytes.feed_bytes(input.as_bytes(), 0).unwrap();
+            bytes.bytes = input.len() as u64;
+
+            prop_assert_eq!(track_floors(input.as_bytes()).unwrap(), bytes);
+        }
+
+        #[test]
+        fn prop_chunks_track_like_whole_input(input in "[()]{0,200}", split in 0..=200usize) {
+            let split = split.min(input.len());
+            let mut tracker = FloorTracker::new();
+            tracker.feed(&input.as_bytes()[..split]).unwrap();
+            tracker.feed(&input.as_bytes()[split..]).unwrap();
+
+            prop_assert_eq!(tracker, track_floors(input.as_bytes()).unwrap());
+        }
+
+        #[test]
+        fn prop_trace_agrees_with_tracker(input in "[()]{0,200}") {
+            let trace = trace_floors(input.as_bytes()).unwrap();
+            let tracker = track_floors(input.as_bytes()).unwrap();
+            let prefixes: Vec<i64> = (0..=input.len())
+                .map(|end| calculate_floor(&input[..end]).unwrap())
+                .collect();
+
+            prop_assert_eq!(trace.tracker(), &tracker);
+            prop_assert_eq!(trace.min_floor(), *prefixes.iter().min().unwrap());
+            prop_assert_eq!(trace.max_floor(), *prefixes.iter().max().unwrap());
+            prop_assert_eq!(
+                trace.floors().map(|(_, visits)| visits.steps).sum::<u64>(),
+                input.len() as u64 + 1
+            );
+            for (floor, visits) in trace.floors() {
+                let first = prefixes.iter().position(|&f| f == floor).unwrap() as u64;
+                prop_assert_eq!(visits.first_reached, first);
+            }
+            prop_assert_eq!(trace.basement_entries().first().copied(), tracker.basement());
+        }
+    }
+}
+
+// --- Day 1: Not Quite Lisp ---
+
+// Santa was hoping for a white Christmas, but his weather machine's "snow" function is powered by stars,
+// and he's fresh out! To save Christmas, he needs you to collect fifty stars by December 25th.
+
+// Collect stars by helping Santa solve puzzles. Two puzzles will be made available on each day in the Advent calendar;
+// the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
+
+// Here's an easy puzzle to warm you up.
+
+// Santa is trying to deliver presents in a large apartment building, but he can't find the right floor - the directions he got are a little confusing.
+// He starts on the ground floor (floor 0) and then follows the instructions one character at a time.
+
+// An opening parenthesis, (, means he should go up one floor, and a closing parenthesis, ), means he should go down one floor.
+
+// The apartment building is very tall, and the basement is very deep; he will never find the top or bottom floors.
+
+// For example:
+
+//     (()) and ()() both result in floor 0.
+//     ((( and (()(()( both result in floor 3.
+//     ))((((( also results in floor 3.
+//     ()) and ))( both result in floor -1 (the first basement level).
+//     ))) and )())()) both result in floor -3.
+
+// To what floor do the instructions take Santa?
+
+// --- Part Two ---
+
+// Now, given the same instructions, find the position of the first character that causes him to enter the basement (floor -1). The first character in the instructions has position 1, the second character has position 2, and so on.
+
+// For example:
+
+//     ) causes him to enter the basement at character position 1.
+//     ()()) causes him to enter the basement at character position 5.
+
+// What is the position of the character that causes Santa to first enter the basement?
@@ -0,0 +1,15 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day10;
+
+impl Generate for Day10 {
+    const DEFAULT_SIZE: usize = 10;
+
+    /// `size` is the number of digits in the starting sequence.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size).map(|_| rng.gen_range('1'..='3')).collect()
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day11;
+
+impl Generate for Day11 {
+    const DEFAULT_SIZE: usize = 8;
+
+    /// `size` is the length of the password. It never contains `i`, `o` or `l`, which the
+    /// solver would otherwise have to increment its way past one password at a time.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| loop {
+                let c = rng.gen_range('a'..='z');
+                if !matches!(c, 'i' | 'o' | 'l') {
+                    break c;
+                }
+            })
+            .collect()
+    }
+}
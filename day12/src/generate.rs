@@ -0,0 +1,57 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day12;
+
+const COLORS: [&str; 6] = ["red", "orange", "yellow", "green", "blue", "violet"];
+
+impl Generate for Day12 {
+    const DEFAULT_SIZE: usize = 5000;
+
+    /// `size` is the number of numbers and strings in the document, nested in arrays and
+    /// objects. Some of the strings are `"red"`.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let mut remaining = size;
+        let mut document = Vec::new();
+
+        while remaining > 0 {
+            document.push(container(&mut rng, &mut remaining, 0));
+        }
+
+        format!("[{}]", document.join(","))
+    }
+}
+
+fn container(rng: &mut StdRng, remaining: &mut usize, depth: usize) -> String {
+    let length = rng.gen_range(1..=6);
+    let mut values = Vec::new();
+
+    while values.len() < length && *remaining > 0 {
+        values.push(value(rng, remaining, depth + 1));
+    }
+
+    if rng.gen_bool(0.5) {
+        format!("[{}]", values.join(","))
+    } else {
+        let members: Vec<String> = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| format!("\"{}\":{}", (b'a' + i as u8) as char, value))
+            .collect();
+        format!("{{{}}}", members.join(","))
+    }
+}
+
+fn value(rng: &mut StdRng, remaining: &mut usize, depth: usize) -> String {
+    if depth < 6 && rng.gen_bool(0.3) {
+        return container(rng, remaining, depth);
+    }
+
+    *remaining -= 1;
+    if rng.gen_bool(0.7) {
+        rng.gen_range(-100..=200).to_string()
+    } else {
+        format!("\"{}\"", COLORS.choose(rng).unwrap())
+    }
+}
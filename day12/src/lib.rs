@@ -0,0 +1,172 @@
+use aoc_common::{ParseError, Solution};
+use json::JsonValue;
+
+pub mod generate;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = JsonValue;
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<JsonValue> {
+        Ok(parse_document(input)?)
+    }
+
+    fn part_one(input: &JsonValue, _: &()) -> anyhow::Result<i64> {
+        Ok(number_sum(input))
+    }
+
+    fn part_two(input: &JsonValue, _: &()) -> anyhow::Result<i64> {
+        Ok(json_sum(input))
+    }
+}
+
+pub fn parse_document(input: &str) -> Result<JsonValue, ParseError> {
+    json::parse(input).map_err(|e| {
+        let (line, column, expected) = match e {
+            json::Error::UnexpectedCharacter { line, column, .. } => (line, column, "valid JSON"),
+            json::Error::UnexpectedEndOfJson => {
+                let line = input.lines().count().max(1);
+                let column = input.lines().last().map_or(0, |text| text.chars().count()) + 1;
+                (line, column, "the rest of the document")
+            }
+            _ => (1, 1, "a JSON document"),
+        };
+
+        ParseError {
+            line,
+            column,
+            expected: vec![expected.to_string()],
+            text: input.lines().nth(line - 1).unwrap_or_default().to_string(),
+        }
+    })
+}
+
+pub fn number_sum(js: &JsonValue) -> i64 {
+    if js.is_number() {
+        return js.as_number().unwrap().as_fixed_point_i64(0).unwrap();
+    }
+
+    if js.is_array() {
+        return js.members().map(number_sum).sum::<i64>();
+    }
+
+    if js.is_object() {
+        return js.entries().map(|entry| number_sum(entry.1)).sum::<i64>();
+    }
+
+    return 0;
+}
+
+pub fn json_sum(js: &JsonValue) -> i64 {
+    json_collect(js)
+}
+
+fn json_collect(js: &JsonValue) -> i64 {
+    if js.is_number() {
+
+        let num: i64 = js.as_number().unwrap().as_fixed_point_i64(0).unwrap();
+        return num;
+    }
+
+    if js.is_array() {
+        return js.members().fold(0, |sum, value| {
+            return sum + json_collect(value);
+        });
+    }
+
+    if js.is_object() {
+        if js.entries().any(|entry| entry.0 == "red" || (entry.1.is_string() && entry.1.as_str().unwrap() == "red")) {
+            return 0;
+        }
+
+        return js.entries().map(|entry| json_collect(entry.1)).sum::<i64>();
+    }
+
+    return 0;
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn sum(input: &str) -> i64 {
+        number_sum(&parse_document(input).unwrap())
+    }
+
+    #[test]
+    fn test_number_sum() {
+        assert_eq!(sum("[1,2,3]"), 6);
+        assert_eq!(sum("{\"a\":2,\"b\":4}"), 6);
+        assert_eq!(sum("[[[3]]]"), 3);
+        assert_eq!(sum("{\"a\":{\"b\":4},\"c\":-1}"), 3);
+        assert_eq!(sum("{\"a\":[-1,1]}"), 0);
+        assert_eq!(sum("[-1,{\"a\":1}]"), 0);
+        assert_eq!(sum("{}"), 0);
+        assert_eq!(sum("[]"), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_document("[1,\n{\"a\" 3}]"),
+            Err(ParseError {
+                line: 2,
+                column: 6,
+                expected: vec!["valid JSON".to_string()],
+                text: "{\"a\" 3}]".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_document("{\"a\":"),
+            Err(ParseError {
+                line: 1,
+                column: 6,
+                expected: vec!["the rest of the document".to_string()],
+                text: "{\"a\":".to_string(),
+            })
+        );
+        assert!(Day12::parse("").is_err());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        assert_eq!(json_collect(&json::parse("{\"a\":2,\"b\":4}").unwrap()), 6);
+        assert_eq!(json_collect(&json::parse("[1,{\"c\":\"red\",\"b\":2},3]").unwrap()), 4);
+        assert_eq!(json_collect(&json::parse("[1,\"red\",3]").unwrap()), 4);
+        assert_eq!(json_collect(&json::parse("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}").unwrap()), 0);
+    }
+}
+
+// --- Day 12: JSAbacusFramework.io ---
+
+// Santa's Accounting-Elves need help balancing the books after a recent order. Unfortunately, their accounting software uses a peculiar storage format. That's where you come in.
+
+// They have a JSON document which contains a variety of things: arrays ([1,2,3]), objects ({"a":1, "b":2}), numbers, and strings. Your first job is to simply find all of the numbers throughout the document and add them together.
+
+// For example:
+
+//     [1,2,3] and {"a":2,"b":4} both have a sum of 6.
+//     [[[3]]] and {"a":{"b":4},"c":-1} both have a sum of 3.
+//     {"a":[-1,1]} and [-1,{"a":1}] both have a sum of 0.
+//     [] and {} both have a sum of 0.
+
+// You will not encounter any strings containing numbers.
+
+// What is the sum of all numbers in the document?
+
+//Uh oh - the Accounting-Elves have realized that they double-counted everything red.
+
+// Ignore any object (and all of its children) which has any property with the value "red". Do this only for objects ({...}), not arrays ([...]).
+
+// [1,2,3] still has a sum of 6.
+// [1,{"c":"red","b":2},3] now has a sum of 4, because the middle object is ignored.
+// {"d":"red","e":[1,2,3,4],"f":5} now has a sum of 0, because the entire structure is ignored.
+// [1,"red",5] has a sum of 6, because "red" in an array has no effect.
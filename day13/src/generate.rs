@@ -0,0 +1,38 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day13;
+
+const NAMES: [&str; 12] = [
+    "Alice", "Bob", "Carol", "David", "Eric", "Frank", "George", "Mallory", "Nina", "Oscar",
+    "Peggy", "Victor",
+];
+
+impl Generate for Day13 {
+    const DEFAULT_SIZE: usize = 8;
+
+    /// `size` is the number of guests, at most 12. The solver tries every seating, so
+    /// keep it small.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let guests: Vec<&str> = NAMES.choose_multiple(&mut rng, size).copied().collect();
+
+        let mut lines = String::new();
+        for guest in &guests {
+            for neighbour in guests.iter().filter(|&neighbour| neighbour != guest) {
+                let change: i32 = rng.gen_range(-100..=100);
+                let verb = if change < 0 { "lose" } else { "gain" };
+
+                lines.push_str(&format!(
+                    "{} would {} {} happiness units by sitting next to {}.\n",
+                    guest,
+                    verb,
+                    change.abs(),
+                    neighbour
+                ));
+            }
+        }
+
+        lines
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day14;
+
+const NAMES: [&str; 9] = [
+    "Dasher", "Dancer", "Prancer", "Vixen", "Comet", "Cupid", "Donner", "Blitzen", "Rudolph",
+];
+
+impl Generate for Day14 {
+    const DEFAULT_SIZE: usize = 9;
+
+    /// `size` is the number of reindeer. Past the nine famous ones, names repeat.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n",
+                    NAMES.choose(&mut rng).unwrap(),
+                    rng.gen_range(1..=30),
+                    rng.gen_range(1..=20),
+                    rng.gen_range(10..=200)
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day15;
+
+const NAMES: [&str; 4] = ["Frosting", "Candy", "Butterscotch", "Sugar"];
+
+impl Generate for Day15 {
+    const DEFAULT_SIZE: usize = 4;
+
+    /// The recipe always has exactly four ingredients, so `size` is ignored.
+    ///
+    /// Like in the real puzzle, each ingredient only adds to one property and takes away
+    /// from the others, which keeps the scores within an `i32`. The first one has 5
+    /// calories, which guarantees a 500 calorie cookie exists.
+    fn generate(seed: u64, _size: usize) -> String {
+        let mut rng = rng(seed);
+
+        NAMES
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let properties: Vec<i32> = (0..4)
+                    .map(|property| {
+                        if property == i {
+                            rng.gen_range(2..=5)
+                        } else if rng.gen_bool(0.4) {
+                            rng.gen_range(-3..=-1)
+                        } else {
+                            0
+                        }
+                    })
+                    .collect();
+
+                format!(
+                    "{}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n",
+                    name,
+                    properties[0],
+                    properties[1],
+                    properties[2],
+                    properties[3],
+                    if i == 0 { 5 } else { rng.gen_range(1..=9) }
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,51 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{Day16, SEARCHED_AUNT};
+
+impl Generate for Day16 {
+    const DEFAULT_SIZE: usize = 500;
+
+    /// `size` is the number of aunts, each remembered by three things. One of them matches
+    /// the readings exactly and another one matches the outdated retroencabulator ranges.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let size = size.max(2);
+
+        let readings = [
+            ("children", SEARCHED_AUNT.children.unwrap()),
+            ("cats", SEARCHED_AUNT.cats.unwrap()),
+            ("samoyeds", SEARCHED_AUNT.samoyeds.unwrap()),
+            ("pomeranians", SEARCHED_AUNT.pomeranians.unwrap()),
+            ("akitas", SEARCHED_AUNT.akitas.unwrap()),
+            ("vizslas", SEARCHED_AUNT.vizslas.unwrap()),
+            ("goldfish", SEARCHED_AUNT.goldfish.unwrap()),
+            ("trees", SEARCHED_AUNT.trees.unwrap()),
+            ("cars", SEARCHED_AUNT.cars.unwrap()),
+            ("perfumes", SEARCHED_AUNT.perfumes.unwrap()),
+        ];
+
+        let exact = rng.gen_range(0..size);
+        let ranged = (exact + rng.gen_range(1..size)) % size;
+
+        (0..size)
+            .map(|i| {
+                let fields: Vec<String> = readings
+                    .choose_multiple(&mut rng, 3)
+                    .map(|&(name, reading)| {
+                        let amount = match name {
+                            _ if i == exact => reading,
+                            "cats" | "trees" if i == ranged => reading + rng.gen_range(1..=3),
+                            "pomeranians" | "goldfish" if i == ranged => rng.gen_range(0..reading),
+                            _ if i == ranged => reading,
+                            _ => rng.gen_range(0..=10),
+                        };
+                        format!("{}: {}", name, amount)
+                    })
+                    .collect();
+
+                format!("Sue {}: {}\n", i + 1, fields.join(", "))
+            })
+            .collect()
+    }
+}
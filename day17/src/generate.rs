@@ -0,0 +1,18 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day17;
+
+impl Generate for Day17 {
+    const DEFAULT_SIZE: usize = 20;
+
+    /// `size` is the number of containers. The solver tries every combination of them, so
+    /// the time doubles with each extra one.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| format!("{}\n", rng.gen_range(5..=50)))
+            .collect()
+    }
+}
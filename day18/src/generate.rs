@@ -0,0 +1,23 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day18;
+
+impl Generate for Day18 {
+    const DEFAULT_SIZE: usize = 100;
+
+    /// `size` is the side of the square grid of lights.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use aoc_common::{generate::rng, Generate};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day19;
+
+impl Generate for Day19 {
+    const DEFAULT_SIZE: usize = 43;
+
+    /// `size` is the number of replacements, at most 200.
+    ///
+    /// Each element is a capital and a lower case letter. The molecule is built by expanding
+    /// `e`, with every element replaced at most once, so it can always be reduced back in
+    /// whatever order the solver picks. About a quarter of the replacements are decoys: they
+    /// apply to the molecule, but produce elements that never appear in it.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        let mut elements: Vec<String> = ('A'..='Z')
+            .flat_map(|upper| ('a'..='z').map(move |lower| format!("{}{}", upper, lower)))
+            .filter(|element| !element.contains('e'))
+            .collect();
+        elements.shuffle(&mut rng);
+
+        let mut expansions: Vec<(String, Vec<String>)> = Vec::new();
+        let mut decoys: Vec<(String, Vec<String>)> = Vec::new();
+        let mut leaves = vec!["e".to_string()];
+
+        for i in 0..size.clamp(1, 200) {
+            let length = rng.gen_range(2..=3);
+            let to = elements.split_off(elements.len() - length);
+
+            if i > 0 && rng.gen_bool(0.25) {
+                let from = leaves.choose(&mut rng).unwrap().clone();
+                decoys.push((from, to));
+            } else {
+                let from = leaves.swap_remove(rng.gen_range(0..leaves.len()));
+                leaves.extend(to.iter().cloned());
+                expansions.push((from, to));
+            }
+        }
+
+        let rules: HashMap<&str, &[String]> = expansions
+            .iter()
+            .map(|(from, to)| (from.as_str(), to.as_slice()))
+            .collect();
+        let molecule = expand("e", &rules);
+
+        let mut replacements = [expansions, decoys].concat();
+        replacements.shuffle(&mut rng);
+        let mut lines: String = replacements
+            .iter()
+            .map(|(from, to)| format!("{} => {}\n", from, to.concat()))
+            .collect();
+
+        lines.push('\n');
+        lines.push_str(&molecule);
+        lines.push('\n');
+        lines
+    }
+}
+
+fn expand(element: &str, rules: &HashMap<&str, &[String]>) -> String {
+    match rules.get(element) {
+        Some(to) => to.iter().map(|element| expand(element, rules)).collect(),
+        None => element.to_string(),
+    }
+}
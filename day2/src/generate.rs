@@ -0,0 +1,24 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day2;
+
+impl Generate for Day2 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` is the number of presents.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{}x{}x{}\n",
+                    rng.gen_range(1..=30),
+                    rng.gen_range(1..=30),
+                    rng.gen_range(1..=30)
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::{self, Display};
+
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+use itertools::iproduct;
+
+pub mod generate;
+pub mod report;
+pub mod strategy;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<Present>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Present>> {
+        Ok(parse_lines(input, parse_present)?)
+    }
+
+    fn part_one(input: &Vec<Present>, _: &()) -> anyhow::Result<u64> {
+        Ok(input.iter().map(calculate_paper).sum())
+    }
+
+    fn part_two(input: &Vec<Present>, _: &()) -> anyhow::Result<u64> {
+        Ok(input.iter().map(calculate_ribbon).sum())
+    }
+}
+
+/// A box shaped present, `LxWxH` in the list.
+///
+/// Dimensions are limited to `u16`, so every area and volume computed from them fits a
+/// `u64` with room to spare.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Present {
+    pub length: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Present {
+    pub fn dimensions(&self) -> [u64; 3] {
+        [self.length as u64, self.width as u64, self.height as u64]
+    }
+
+    /// The dimensions from smallest to largest.
+    pub fn sorted_dimensions(&self) -> [u64; 3] {
+        let mut dimensions = self.dimensions();
+        dimensions.sort_unstable();
+        dimensions
+    }
+}
+
+impl Display for Present {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}x{}", self.length, self.width, self.height)
+    }
+}
+
+pub fn parse_present(input: &str) -> Result<Present, ParseError> {
+    peg::parser! {
+        grammar present_parser() for str {
+            rule dimension() -> u16 = n:$(quiet!{"-"? ['0'..='9']+} / expected!("a dimension")) {? dimension(n) }
+
+            pub rule parse() -> Present = length:dimension() "x" width:dimension() "x" height:dimension() {
+                Present {
+                    length,
+                    width,
+                    height,
+                }
+            }
+        }
+    }
+
+    present_parser::parse(input).map_err(|e| ParseError::from_peg(input, e))
+}
+
+fn dimension(n: &str) -> Result<u16, &'static str> {
+    if n.starts_with('-') {
+        Err("a non-negative dimension")
+    } else {
+        n.parse().or(Err("a dimension of at most 65535"))
+    }
+}
+
+pub fn calculate_paper(present: &Present) -> u64 {
+    let dimensions = present.dimensions();
+    let sides: Vec<u64> = iproduct!(0..3, 0..3)
+        .filter(|(a, b)| a != b)
+        .map(|(a, b)| dimensions[a] * dimensions[b])
+        .collect();
+    return *sides.iter().min().unwrap() + sides.iter().sum::<u64>();
+}
+
+pub fn calculate_ribbon(present: &Present) -> u64 {
+    let dimensions = present.dimensions();
+    let bow = dimensions[0] * dimensions[1] * dimensions[2];
+
+    let wrap = iproduct!(0..3, 0..3)
+        .filter(|(a, b)| a != b)
+        .map(|(a, b)| 2 * dimensions[a] + 2 * dimensions[b])
+        .min()
+        .unwrap();
+
+    return bow + wrap;
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseErrors;
+
+    use super::*;
+
+    fn present(length: u16, width: u16, height: u16) -> Present {
+        Present {
+            length,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_calculate_paper() {
+        assert_eq!(calculate_paper(&present(2, 3, 4)), 58);
+        assert_eq!(calculate_paper(&present(1, 1, 10)), 43);
+    }
+    #[test]
+    fn test_calculate_ribon() {
+        assert_eq!(calculate_ribbon(&present(2, 3, 4)), 34);
+        assert_eq!(calculate_ribbon(&present(1, 1, 10)), 14);
+        assert_eq!(
+            calculate_ribbon(&present(u16::MAX, u16::MAX, u16::MAX)),
+            281_462_092_005_375 + 262_140
+        );
+    }
+
+    #[test]
+    fn test_parse_present() {
+        assert_eq!(parse_present("2x3x4"), Ok(present(2, 3, 4)));
+        assert_eq!(parse_present("0x65535x10"), Ok(present(0, 65535, 10)));
+        assert_eq!(present(0, 65535, 10).to_string(), "0x65535x10");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let errors = Day2::parse("2x3x4\n2x3\n1x-2x3\n1x2x65536\n1x2x3x4\n2xx3").unwrap_err();
+        let errors = errors.downcast::<ParseErrors>().unwrap();
+
+        assert_eq!(
+            errors
+                .0
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.join(", ")))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, 4, "\"x\"".to_string()),
+                (3, 5, "a non-negative dimension".to_string()),
+                (4, 10, "a dimension of at most 65535".to_string()),
+                (5, 6, "EOF".to_string()),
+                (6, 3, "a dimension".to_string()),
+            ]
+        );
+    }
+}
+
+// --- Day 2: I Was Told There Would Be No Math ---
+
+// The elves are running low on wrapping paper, and so they need to submit an order for more.
+// They have a list of the dimensions (length l, width w, and height h) of each present, and only want to order exactly as much as they need.
+
+// Fortunately, every present is a box (a perfect right rectangular prism),
+// which makes calculating the required wrapping paper for each gift a little easier:
+// find the surface area of the box, which is 2*l*w + 2*w*h + 2*h*l.
+// The elves also need a little extra paper for each present: the area of the smallest side.
+
+// For example:
+
+//     A present with dimensions 2x3x4 requires 2*6 + 2*12 + 2*8 =
+//       52 square feet of wrapping paper plus 6 square feet of slack, for a total of 58 square feet.
+//     A present with dimensions 1x1x10 requires 2*1 + 2*10 + 2*10 =
+//       42 square feet of wrapping paper plus 1 square foot of slack, for a total of 43 square feet.
+
+// All numbers in the elves' list are in feet. How many total square feet of wrapping paper should they order?
+
+// --- Part Two ---
+
+// The elves are also running low on ribbon. Ribbon is all the same width, so they only have to worry about the length they need to order, which they would again like to be exact.
+
+// The ribbon required to wrap a present is the shortest distance around its sides, or the smallest perimeter of any one face. Each present also requires a bow made out of ribbon as well; the feet of ribbon required for the perfect bow is equal to the cubic feet of volume of the present. Don't ask how they tie the bow, though; they'll never tell.
+
+// For example:
+
+//     A present with dimensions 2x3x4 requires 2+2+3+3 = 10 feet of ribbon to wrap the present plus 2*3*4 = 24 feet of ribbon for the bow, for a total of 34 feet.
+//     A present with dimensions 1x1x10 requires 1+1+1+1 = 4 feet of ribbon to wrap the present plus 1*1*10 = 10 feet of ribbon for the bow, for a total of 14 feet.
+
+// How many total feet of ribbon should they order?
@@ -1,89 +1,70 @@
-use itertools::iproduct;
-
-fn main() -> anyhow::Result<()> {
-    let s = include_str!("input.txt")
-        .lines()
-        .map(|line| {
-            line.split('x')
-                .map(str::parse::<i32>)
-                .map(std::result::Result::unwrap)
-                .collect::<Vec<i32>>()
-        })
-        .map(|line| (calculate_paper(&line), calculate_ribbon(&line)))
-        .fold((0, 0), |(init_paper, init_ribbon), (paper, ribbon)| {
-            (init_paper + paper, init_ribbon + ribbon)
-        });
-
-    println!("[Part one]: {}", s.0);
-    println!("[Part two]: {}", s.1);
-
-    Ok(())
+use aoc_common::{input::Source, solve, Solution};
+use clap::{Parser, ValueEnum};
+use day2::{
+    report::Report,
+    strategy::{compare, strategies},
+    Day2,
+};
+
+/// Day 2: I Was Told There Would Be No Math.
+#[derive(Parser)]
+struct Cli {
+    /// Input file, or `-` to read it from stdin [default: the embedded input]
+    input: Option<Source>,
+
+    /// List the paper and ribbon every present takes, with statistics, instead of the totals
+    #[arg(long)]
+    report: bool,
+
+    /// Compare the total paper and ribbon every wrapping strategy needs
+    #[arg(long, conflicts_with = "report")]
+    compare: bool,
+
+    /// Format of the report
+    #[arg(short, long, value_enum, default_value_t, requires = "report")]
+    format: Format,
 }
 
-fn calculate_paper(dimensions: &[i32]) -> i32 {
-    let sides: Vec<i32> = iproduct!(0..3, 0..3)
-        .filter(|(a, b)| a != b)
-        .map(|(a, b)| dimensions[a] * dimensions[b])
-        .collect();
-    return *sides.iter().min().unwrap() + sides.iter().sum::<i32>();
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, ValueEnum)]
+enum Format {
+    /// A table followed by the statistics
+    #[default]
+    Text,
+    /// One row per present, without the statistics
+    Csv,
+    /// Every present and the statistics
+    Json,
 }
 
-fn calculate_ribbon(dimensions: &[i32]) -> i32 {
-    let bow = dimensions[0] * dimensions[1] * dimensions[2];
-
-    let wrap = iproduct!(0..3, 0..3)
-        .filter(|(a, b)| a != b)
-        .map(|(a, b)| 2 * dimensions[a] + 2 * dimensions[b])
-        .min()
-        .unwrap();
-
-    return bow + wrap;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_calculate_paper() {
-        assert_eq!(calculate_paper(&[2, 3, 4]), 58);
-        assert_eq!(calculate_paper(&[1, 1, 10]), 43);
-    }
-    #[test]
-    fn test_calculate_ribon() {
-        assert_eq!(calculate_ribbon(&[2, 3, 4]), 34);
-        assert_eq!(calculate_ribbon(&[1, 1, 10]), 14);
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let input = cli.input.unwrap_or_default().read(Day2::INPUT)?;
+
+    if cli.compare {
+        let strategies = strategies();
+        println!("{:<18} {:>10} {:>10}", "Strategy", "Paper", "Ribbon");
+        for (name, material) in compare(&Day2::parse(&input)?, &strategies) {
+            println!(
+                "{:<18} {:>10} {:>10}",
+                name, material.paper, material.ribbon
+            );
+        }
+        return Ok(());
     }
-}
 
-// --- Day 2: I Was Told There Would Be No Math ---
-
-// The elves are running low on wrapping paper, and so they need to submit an order for more.
-// They have a list of the dimensions (length l, width w, and height h) of each present, and only want to order exactly as much as they need.
-
-// Fortunately, every present is a box (a perfect right rectangular prism),
-// which makes calculating the required wrapping paper for each gift a little easier:
-// find the surface area of the box, which is 2*l*w + 2*w*h + 2*h*l.
-// The elves also need a little extra paper for each present: the area of the smallest side.
-
-// For example:
-
-//     A present with dimensions 2x3x4 requires 2*6 + 2*12 + 2*8 =
-//       52 square feet of wrapping paper plus 6 square feet of slack, for a total of 58 square feet.
-//     A present with dimensions 1x1x10 requires 2*1 + 2*10 + 2*10 =
-//       42 square feet of wrapping paper plus 1 square foot of slack, for a total of 43 square feet.
-
-// All numbers in the elves' list are in feet. How many total square feet of wrapping paper should they order?
-
-// --- Part Two ---
-
-// The elves are also running low on ribbon. Ribbon is all the same width, so they only have to worry about the length they need to order, which they would again like to be exact.
-
-// The ribbon required to wrap a present is the shortest distance around its sides, or the smallest perimeter of any one face. Each present also requires a bow made out of ribbon as well; the feet of ribbon required for the perfect bow is equal to the cubic feet of volume of the present. Don't ask how they tie the bow, though; they'll never tell.
-
-// For example:
+    if !cli.report {
+        for answer in solve::<Day2>(&input, None, &())? {
+            println!("{}", answer);
+        }
+        return Ok(());
+    }
 
-//     A present with dimensions 2x3x4 requires 2+2+3+3 = 10 feet of ribbon to wrap the present plus 2*3*4 = 24 feet of ribbon for the bow, for a total of 34 feet.
-//     A present with dimensions 1x1x10 requires 1+1+1+1 = 4 feet of ribbon to wrap the present plus 1*1*10 = 10 feet of ribbon for the bow, for a total of 14 feet.
+    let report = Report::new(&Day2::parse(&input)?);
+    match cli.format {
+        Format::Text => print!("{}", report),
+        Format::Csv => print!("{}", report.to_csv()),
+        Format::Json => println!("{}", report.to_json().pretty(2)),
+    }
 
-// How many total feet of ribbon should they order?
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use std::fmt::{self, Display};
+
+use json::{object, JsonValue};
+
+use crate::{calculate_paper, calculate_ribbon, Present};
+
+/// How much paper and ribbon a single present takes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Wrapping {
+    pub present: Present,
+    /// Paper for every side, including the slack.
+    pub paper: u64,
+    /// Extra paper, the area of the smallest side.
+    pub slack: u64,
+    /// Ribbon around the smallest perimeter, including the bow.
+    pub ribbon: u64,
+    /// Ribbon for the bow, as long as the present's volume.
+    pub bow: u64,
+}
+
+impl Wrapping {
+    pub fn new(present: &Present) -> Wrapping {
+        let [small, medium, large] = present.sorted_dimensions();
+
+        Wrapping {
+            present: *present,
+            paper: calculate_paper(present),
+            slack: small * medium,
+            ribbon: calculate_ribbon(present),
+            bow: small * medium * large,
+        }
+    }
+}
+
+/// Totals and highlights over every present in a list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    pub presents: usize,
+    pub total_paper: u64,
+    pub total_ribbon: u64,
+    /// The present with the largest volume, the first one listed on ties.
+    pub largest: Option<Present>,
+    /// Median paper per present, the mean of the middle two for an even count.
+    pub median_paper: Option<f64>,
+}
+
+impl Statistics {
+    pub fn new(wrappings: &[Wrapping]) -> Statistics {
+        let mut papers: Vec<u64> = wrappings.iter().map(|w| w.paper).collect();
+        papers.sort_unstable();
+
+        let median_paper = match papers.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(papers[n / 2] as f64),
+            n => Some((papers[n / 2 - 1] + papers[n / 2]) as f64 / 2.0),
+        };
+
+        Statistics {
+            presents: wrappings.len(),
+            total_paper: papers.iter().sum(),
+            total_ribbon: wrappings.iter().map(|w| w.ribbon).sum(),
+            largest: wrappings
+                .iter()
+                .rev()
+                .max_by_key(|w| w.bow)
+                .map(|w| w.present),
+            median_paper,
+        }
+    }
+}
+
+/// Every present's wrapping, in list order, with statistics over all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub wrappings: Vec<Wrapping>,
+    pub statistics: Statistics,
+}
+
+const CSV_HEADER: &str = "length,width,height,paper,slack,ribbon,bow";
+
+impl Report {
+    pub fn new(presents: &[Present]) -> Report {
+        let wrappings: Vec<Wrapping> = presents.iter().map(Wrapping::new).collect();
+        let statistics = Statistics::new(&wrappings);
+
+        Report {
+            wrappings,
+            statistics,
+        }
+    }
+
+    /// One row per present; the statistics are left out, as they do not fit the table.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+        for w in &self.wrappings {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                w.present.length,
+                w.present.width,
+                w.present.height,
+                w.paper,
+                w.slack,
+                w.ribbon,
+                w.bow
+            ));
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        let stats = &self.statistics;
+
+        object! {
+            presents: self.wrappings.iter().map(wrapping_json).collect::<Vec<_>>(),
+            statistics: object! {
+                presents: stats.presents,
+                total_paper: stats.total_paper,
+                total_ribbon: stats.total_ribbon,
+                largest: stats.largest.as_ref().map_or(JsonValue::Null, present_json),
+                median_paper: stats.median_paper,
+            },
+        }
+    }
+}
+
+/// A table of every present followed by the statistics.
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>17} {:>10} {:>8} {:>10} {:>8}",
+            "Present", "Paper", "Slack", "Ribbon", "Bow"
+        )?;
+        for w in &self.wrappings {
+            writeln!(
+                f,
+                "{:>17} {:>10} {:>8} {:>10} {:>8}",
+                w.present.to_string(),
+                w.paper,
+                w.slack,
+                w.ribbon,
+                w.bow
+            )?;
+        }
+
+        let stats = &self.statistics;
+        writeln!(f)?;
+        writeln!(f, "Presents:     {}", stats.presents)?;
+        writeln!(f, "Total paper:  {}", stats.total_paper)?;
+        writeln!(f, "Total ribbon: {}", stats.total_ribbon)?;
+        if let Some(largest) = stats.largest {
+            writeln!(f, "Largest:      {}", largest)?;
+        }
+        if let Some(median) = stats.median_paper {
+            writeln!(f, "Median paper: {}", median)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn present_json(present: &Present) -> JsonValue {
+    object! {
+        length: present.length,
+        width: present.width,
+        height: present.height,
+    }
+}
+
+fn wrapping_json(wrapping: &Wrapping) -> JsonValue {
+    let mut json = present_json(&wrapping.present);
+    json["paper"] = wrapping.paper.into();
+    json["slack"] = wrapping.slack.into();
+    json["ribbon"] = wrapping.ribbon.into();
+    json["bow"] = wrapping.bow.into();
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_present;
+
+    fn report(presents: &[&str]) -> Report {
+        let presents: Vec<Present> = presents.iter().map(|p| parse_present(p).unwrap()).collect();
+        Report::new(&presents)
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(
+            Wrapping::new(&parse_present("2x3x4").unwrap()),
+            Wrapping {
+                present: parse_present("2x3x4").unwrap(),
+                paper: 58,
+                slack: 6,
+                ribbon: 34,
+                bow: 24,
+            }
+        );
+    }
+
+    #[test]
+    fn test_statistics() {
+        let stats = report(&["2x3x4", "1x1x10", "4x3x2", "1x1x1"]).statistics;
+
+        assert_eq!(stats.presents, 4);
+        assert_eq!(stats.total_paper, 58 + 43 + 58 + 7);
+        assert_eq!(stats.total_ribbon, 34 + 14 + 34 + 5);
+        assert_eq!(stats.largest, parse_present("2x3x4").ok());
+        assert_eq!(stats.median_paper, Some(50.5));
+
+        let empty = report(&[]).statistics;
+        assert_eq!((empty.largest, empty.median_paper), (None, None));
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            report(&["2x3x4", "1x1x10"]).to_csv(),
+            "length,width,height,paper,slack,ribbon,bow\n2,3,4,58,6,34,24\n1,1,10,43,1,14,10\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json = report(&["2x3x4", "1x1x10"]).to_json();
+
+        assert_eq!(json["presents"].len(), 2);
+        assert_eq!(json["presents"][1]["height"], 10);
+        assert_eq!(json["presents"][1]["slack"], 1);
+        assert_eq!(json["statistics"]["largest"]["length"], 2);
+        assert_eq!(json["statistics"]["median_paper"], 50.5);
+    }
+}
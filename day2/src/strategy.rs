@@ -0,0 +1,190 @@
+use crate::{calculate_paper, calculate_ribbon, Present};
+
+/// A rule set for how much paper and ribbon a present takes.
+pub trait WrappingStrategy {
+    fn name(&self) -> &str;
+
+    fn paper(&self, present: &Present) -> u64;
+
+    fn ribbon(&self, present: &Present) -> u64;
+
+    /// Material for every present in a list.
+    fn material(&self, presents: &[Present]) -> Material {
+        presents
+            .iter()
+            .map(|present| Material {
+                paper: self.paper(present),
+                ribbon: self.ribbon(present),
+            })
+            .sum()
+    }
+}
+
+/// Square feet of paper and feet of ribbon.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Material {
+    pub paper: u64,
+    pub ribbon: u64,
+}
+
+impl std::iter::Sum for Material {
+    fn sum<I: Iterator<Item = Material>>(iter: I) -> Material {
+        iter.fold(Material::default(), |total, m| Material {
+            paper: total.paper + m.paper,
+            ribbon: total.ribbon + m.ribbon,
+        })
+    }
+}
+
+/// The elves' rules from the puzzle: slack the size of the smallest side, ribbon around
+/// the smallest perimeter and a bow as long as the volume.
+pub struct Elves;
+
+impl WrappingStrategy for Elves {
+    fn name(&self) -> &str {
+        "elves"
+    }
+
+    fn paper(&self, present: &Present) -> u64 {
+        calculate_paper(present)
+    }
+
+    fn ribbon(&self, present: &Present) -> u64 {
+        calculate_ribbon(present)
+    }
+}
+
+/// Slack the size of the largest side, for clumsier wrappers.
+pub struct LargestSlack;
+
+impl WrappingStrategy for LargestSlack {
+    fn name(&self) -> &str {
+        "largest-slack"
+    }
+
+    fn paper(&self, present: &Present) -> u64 {
+        let [_, medium, large] = present.sorted_dimensions();
+        surface(present) + medium * large
+    }
+
+    fn ribbon(&self, present: &Present) -> u64 {
+        calculate_ribbon(present)
+    }
+}
+
+/// Ribbon around the largest perimeter, so it also goes round the longest way.
+pub struct LargestPerimeter;
+
+impl WrappingStrategy for LargestPerimeter {
+    fn name(&self) -> &str {
+        "largest-perimeter"
+    }
+
+    fn paper(&self, present: &Present) -> u64 {
+        calculate_paper(present)
+    }
+
+    fn ribbon(&self, present: &Present) -> u64 {
+        let [small, medium, large] = present.sorted_dimensions();
+        2 * medium + 2 * large + small * medium * large
+    }
+}
+
+/// A box standing on its length by width side, with a lid that overlaps the walls.
+///
+/// The box and the lid are wrapped separately and need no slack; the ribbon only ties
+/// the lid shut, around the box's walls, plus the bow.
+pub struct GiftBox {
+    /// How far down the walls the lid reaches.
+    pub lid_depth: u64,
+}
+
+impl WrappingStrategy for GiftBox {
+    fn name(&self) -> &str {
+        "gift-box"
+    }
+
+    fn paper(&self, present: &Present) -> u64 {
+        let [length, width, height] = present.dimensions();
+        let walls = 2 * length * height + 2 * width * height;
+        let lid = length * width + 2 * self.lid_depth.min(height) * (length + width);
+
+        length * width + walls + lid
+    }
+
+    fn ribbon(&self, present: &Present) -> u64 {
+        let [length, width, height] = present.dimensions();
+        2 * length + 2 * width + length * width * height
+    }
+}
+
+/// Every strategy, for comparing them on the same list.
+pub fn strategies() -> Vec<Box<dyn WrappingStrategy>> {
+    vec![
+        Box::new(Elves),
+        Box::new(LargestSlack),
+        Box::new(LargestPerimeter),
+        Box::new(GiftBox { lid_depth: 1 }),
+    ]
+}
+
+/// The material each strategy needs for the same presents, in the order given.
+pub fn compare<'a>(
+    presents: &[Present],
+    strategies: &'a [Box<dyn WrappingStrategy>],
+) -> Vec<(&'a str, Material)> {
+    strategies
+        .iter()
+        .map(|strategy| (strategy.name(), strategy.material(presents)))
+        .collect()
+}
+
+fn surface(present: &Present) -> u64 {
+    let [length, width, height] = present.dimensions();
+    2 * length * width + 2 * width * height + 2 * height * length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_present;
+
+    #[test]
+    fn test_strategies() {
+        let present = parse_present("2x3x4").unwrap();
+
+        assert_eq!((Elves.paper(&present), Elves.ribbon(&present)), (58, 34));
+        assert_eq!(LargestSlack.paper(&present), 52 + 12);
+        assert_eq!(LargestPerimeter.ribbon(&present), 14 + 24);
+
+        let gift_box = GiftBox { lid_depth: 1 };
+        assert_eq!(gift_box.paper(&present), 6 + 40 + 6 + 10);
+        assert_eq!(gift_box.ribbon(&present), 10 + 24);
+        assert_eq!(GiftBox { lid_depth: 9 }.paper(&present), 6 + 40 + 6 + 40);
+    }
+
+    #[test]
+    fn test_compare() {
+        let presents = [
+            parse_present("2x3x4").unwrap(),
+            parse_present("1x1x10").unwrap(),
+        ];
+        let strategies = strategies();
+        let compared = compare(&presents, &strategies);
+
+        assert_eq!(
+            compared[0],
+            (
+                "elves",
+                Material {
+                    paper: 101,
+                    ribbon: 48
+                }
+            )
+        );
+        assert_eq!(
+            compared.iter().map(|&(name, _)| name).collect::<Vec<_>>(),
+            vec!["elves", "largest-slack", "largest-perimeter", "gift-box"]
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day20;
+
+impl Generate for Day20 {
+    const DEFAULT_SIZE: usize = 36_000_000;
+
+    /// `size` is the largest number of presents to look for; the puzzle number is picked
+    /// between half of it and all of it.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let size = size.max(2);
+
+        format!("{}\n", rng.gen_range(size / 2..=size))
+    }
+}
@@ -0,0 +1,160 @@
+use std::vec;
+
+use anyhow::{anyhow, bail};
+use aoc_common::Solution;
+
+pub mod generate;
+
+aoc_common::options! {
+    pub struct Options {
+        /// Presents each elf delivers per house in part one.
+        presents_per_elf: usize = 10,
+        /// Presents each elf delivers per house in part two.
+        lazy_presents_per_elf: usize = 11,
+        /// Houses each elf visits before stopping in part two.
+        houses_per_lazy_elf: usize = 50,
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = usize;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Options = Options;
+
+    fn parse(input: &str) -> anyhow::Result<usize> {
+        Ok(input.trim().parse()?)
+    }
+
+    fn part_one(&presents: &usize, options: &Options) -> anyhow::Result<usize> {
+        if options.presents_per_elf == 0 {
+            bail!("Elves must deliver at least one present per house");
+        }
+
+        count_presents(presents, options.presents_per_elf)
+    }
+
+    fn part_two(&presents: &usize, options: &Options) -> anyhow::Result<usize> {
+        if options.lazy_presents_per_elf == 0 {
+            bail!("Elves must deliver at least one present per house");
+        }
+        if options.houses_per_lazy_elf == 0 {
+            bail!("Elves must visit at least one house");
+        }
+
+        count_presents_2(
+            presents,
+            options.lazy_presents_per_elf,
+            options.houses_per_lazy_elf,
+        )
+    }
+}
+
+pub fn count_presents(n: usize, presents_per_elf: usize) -> anyhow::Result<usize> {
+    // Elf `i` alone brings `i * presents_per_elf` to house `i`, so no later house is needed.
+    let mut houses = vec![0; 1 + n.div_ceil(presents_per_elf)];
+
+    for i in 1..=houses.len() {
+        for j in (i..=houses.len()).step_by(i) {
+            if let Some(value) = houses.get_mut(j) {
+                *value += i * presents_per_elf;
+            }
+        }
+    }
+
+    houses
+        .iter()
+        .position(|&presents| presents >= n)
+        .ok_or_else(|| anyhow!("No house gets {} presents", n))
+}
+
+pub fn count_presents_2(
+    n: usize,
+    presents_per_elf: usize,
+    houses_per_elf: usize,
+) -> anyhow::Result<usize> {
+    let mut houses = vec![0; 1 + n.div_ceil(presents_per_elf)];
+
+    for i in 1..=houses.len() {
+        for j in (i..).step_by(i).take(houses_per_elf) {
+            if let Some(value) = houses.get_mut(j) {
+                *value += i * presents_per_elf;
+            }
+        }
+    }
+
+    houses
+        .iter()
+        .position(|&presents| presents >= n)
+        .ok_or_else(|| anyhow!("No house gets {} presents", n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsing() {
+        assert_eq!(count_presents(10, 10).unwrap(), 1);
+        assert_eq!(count_presents(20, 10).unwrap(), 2);
+        assert_eq!(count_presents(35, 10).unwrap(), 3);
+        assert_eq!(count_presents(100, 10).unwrap(), 6);
+        assert_eq!(count_presents(150, 10).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_zero_options() {
+        let options = |presents_per_elf, lazy_presents_per_elf, houses_per_lazy_elf| Options {
+            presents_per_elf,
+            lazy_presents_per_elf,
+            houses_per_lazy_elf,
+        };
+
+        assert!(Day20::part_one(&150, &options(0, 11, 50)).is_err());
+        assert!(Day20::part_two(&150, &options(10, 0, 50)).is_err());
+        assert!(Day20::part_two(&150, &options(10, 11, 0)).is_err());
+        assert_eq!(Day20::part_two(&150, &options(10, 11, 1)).unwrap(), 14);
+    }
+}
+
+
+// --- Day 20: Infinite Elves and Infinite Houses ---
+
+// To keep the Elves busy, Santa has them deliver some presents by hand, door-to-door. He sends them down a street with infinite houses numbered sequentially: 1, 2, 3, 4, 5, and so on.
+
+// Each Elf is assigned a number, too, and delivers presents to houses based on that number:
+
+//     The first Elf (number 1) delivers presents to every house: 1, 2, 3, 4, 5, ....
+//     The second Elf (number 2) delivers presents to every second house: 2, 4, 6, 8, 10, ....
+//     Elf number 3 delivers presents to every third house: 3, 6, 9, 12, 15, ....
+
+// There are infinitely many Elves, numbered starting with 1. Each Elf delivers presents equal to ten times his or her number at each house.
+
+// So, the first nine houses on the street end up like this:
+
+// House 1 got 10 presents.
+// House 2 got 30 presents.
+// House 3 got 40 presents.
+// House 4 got 70 presents.
+// House 5 got 60 presents.
+// House 6 got 120 presents.
+// House 7 got 80 presents.
+// House 8 got 150 presents.
+// House 9 got 130 presents.
+
+// The first house gets 10 presents: it is visited only by Elf 1, which delivers 1 * 10 = 10 presents. The fourth house gets 70 presents, because it is visited by Elves 1, 2, and 4, for a total of 10 + 20 + 40 = 70 presents.
+
+// What is the lowest house number of the house to get at least as many presents as the number in your puzzle input?
+
+// Your puzzle input is 36000000.
+ 
+// --- Part Two ---
+
+// The Elves decide they don't want to visit an infinite number of houses. Instead, each Elf will stop after delivering presents to 50 houses. To make up for it, they decide to deliver presents equal to eleven times their number at each house.
+
+// With these changes, what is the new lowest house number of the house to get at least as many presents as the number in your puzzle input?
@@ -0,0 +1,38 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day23;
+
+/// Tripling a register more often than this could overflow it.
+const MAX_TRIPLES: usize = 30;
+
+impl Generate for Day23 {
+    const DEFAULT_SIZE: usize = 46;
+
+    /// `size` is the number of instructions. Jumps only ever go forward, so every program
+    /// terminates.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let mut triples = 0;
+
+        (0..size)
+            .map(|i| {
+                let register = ['a', 'b'].choose(&mut rng).unwrap();
+                let offset = rng.gen_range(1..=(size - i).min(6));
+
+                let instruction = match rng.gen_range(0..6) {
+                    0 => format!("hlf {}", register),
+                    1 if triples < MAX_TRIPLES => {
+                        triples += 1;
+                        format!("tpl {}", register)
+                    }
+                    2 => format!("jmp +{}", offset),
+                    3 => format!("jie {}, +{}", register, offset),
+                    4 => format!("jio {}, +{}", register, offset),
+                    _ => format!("inc {}", register),
+                };
+                instruction + "\n"
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+
+pub mod generate;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<Instruction>;
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+        Ok(parse_lines(input, map_line)?)
+    }
+
+    fn part_one(instructions: &Vec<Instruction>, _: &()) -> anyhow::Result<i64> {
+        run_program(0, instructions)
+    }
+
+    fn part_two(instructions: &Vec<Instruction>, _: &()) -> anyhow::Result<i64> {
+        run_program(1, instructions)
+    }
+}
+
+/// Runs the program with register `a` preset and returns the final value of register `b`.
+pub fn run_program(a: i64, instructions: &[Instruction]) -> anyhow::Result<i64> {
+    let mut registers: HashMap<char, i64> = HashMap::new();
+    registers.insert('a', a);
+    registers.insert('b', 0);
+
+    let final_register = execute_instructions(&registers, instructions);
+
+    final_register
+        .get(&'b')
+        .copied()
+        .context("Register b is missing")
+}
+
+pub fn execute_instructions(
+    register: &HashMap<char, i64>,
+    instructions: &[Instruction],
+) -> HashMap<char, i64> {
+    let mut registers = register.clone();
+
+    let mut pointer: i64 = 0;
+
+    while pointer >= 0 && pointer < instructions.len() as i64 {
+        match &instructions.get(pointer as usize).unwrap() {
+            Instruction::Jump(p) => {
+                pointer += p;
+            }
+            Instruction::JumpIfEven(r, p) => {
+                if registers.get(r).unwrap() % 2 == 0 {
+                    pointer += p;
+                } else {
+                    pointer += 1;
+                }
+            }
+            Instruction::JumpIfOne(r, p) => {
+                if registers.get(r).unwrap() == &1 {
+                    pointer += p;
+                } else {
+                    pointer += 1;
+                }
+            }
+            Instruction::Half(r) => {
+                if let Some(x) = registers.get_mut(r) {
+                    *x >>= 1;
+                    pointer += 1;
+                }
+            }
+            Instruction::Triple(r) => {
+                if let Some(x) = registers.get_mut(r) {
+                    *x *= 3;
+                    pointer += 1;
+                }
+            }
+            Instruction::Increment(r) => {
+                if let Some(x) = registers.get_mut(r) {
+                    *x += 1;
+                    pointer += 1;
+                }
+            }
+        }
+    }
+
+    registers
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Jump(i64),
+    JumpIfEven(char, i64),
+    JumpIfOne(char, i64),
+    Half(char),
+    Triple(char),
+    Increment(char),
+}
+
+// hlf r sets register r to half its current value, then continues with the next instruction.
+// tpl r sets register r to triple its current value, then continues with the next instruction.
+// inc r increments register r, adding 1 to it, then continues with the next instruction.
+// jmp offset is a jump; it continues with the instruction offset away relative to itself.
+// jie r, offset is like jmp, but only jumps if register r is even ("jump if even").
+// jio r, offset is like jmp, but only jumps if register r is 1 ("jump if one", not odd).
+
+pub fn map_line(line: &str) -> Result<Instruction, ParseError> {
+    peg::parser! {
+        grammar instruction_parser() for str {
+            rule register() -> char = quiet!{"a" { 'a' } / "b" { 'b' }} / expected!("register a or b")
+
+            rule offset() -> i64 = n:$(quiet!{['+' | '-']? ['0'..='9']+} / expected!("an offset")) {?
+                aoc_common::parse::number(n)
+            }
+
+            pub rule parse() -> Instruction
+                = "hlf " r:register() { Instruction::Half(r) }
+                / "tpl " r:register() { Instruction::Triple(r) }
+                / "inc " r:register() { Instruction::Increment(r) }
+                / "jmp " o:offset() { Instruction::Jump(o) }
+                / "jie " r:register() ", " o:offset() { Instruction::JumpIfEven(r, o) }
+                / "jio " r:register() ", " o:offset() { Instruction::JumpIfOne(r, o) }
+        }
+    }
+
+    instruction_parser::parse(line).map_err(|e| ParseError::from_peg(line, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseErrors;
+
+    use super::*;
+
+    #[test]
+    fn test_number_sum() {
+        assert_eq!(map_line("jmp +23"), Ok(Instruction::Jump(23)));
+        assert_eq!(map_line("jmp -23"), Ok(Instruction::Jump(-23)));
+        assert_eq!(map_line("jio a, -23"), Ok(Instruction::JumpIfOne('a', -23)));
+        assert_eq!(
+            map_line("jie a, -23"),
+            Ok(Instruction::JumpIfEven('a', -23))
+        );
+        assert_eq!(map_line("hlf a"), Ok(Instruction::Half('a')));
+        assert_eq!(map_line("tpl a"), Ok(Instruction::Triple('a')));
+        assert_eq!(map_line("inc a"), Ok(Instruction::Increment('a')));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day23::parse("inc a\njio b, +2\n\n").unwrap(),
+            vec![Instruction::Increment('a'), Instruction::JumpIfOne('b', 2)]
+        );
+
+        let errors = Day23::parse("inc a\ndec a\ninc c\njie a +2")
+            .unwrap_err()
+            .downcast::<ParseErrors>()
+            .unwrap();
+        assert_eq!(
+            errors
+                .0
+                .iter()
+                .map(|e| (e.line, e.column))
+                .collect::<Vec<_>>(),
+            vec![(2, 1), (3, 5), (4, 6)]
+        );
+    }
+
+    #[test]
+    fn test_execute_instructions() {
+        let mut map = HashMap::new();
+        map.insert('a', 0);
+        map.insert('b', 0);
+
+        let instructions = vec![
+            Instruction::Increment('a'),
+            Instruction::JumpIfOne('a', 2),
+            Instruction::Triple('a'),
+            Instruction::Increment('a'),
+        ];
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert('a', 2);
+        expected_map.insert('b', 0);
+
+        assert_eq!(execute_instructions(&map, &instructions), expected_map);
+    }
+}
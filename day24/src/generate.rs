@@ -0,0 +1,39 @@
+use std::collections::BTreeSet;
+
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day24;
+
+impl Generate for Day24 {
+    const DEFAULT_SIZE: usize = 28;
+
+    /// `size` is the number of packages, at least 24.
+    ///
+    /// The packages are dealt into twelve piles which are then topped up to the same
+    /// weight, so they can be split evenly into both three and four groups.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let dealt = size.max(24) - 12;
+
+        loop {
+            let mut packages = BTreeSet::new();
+            while packages.len() < dealt {
+                packages.insert(rng.gen_range(1..=dealt as u64 * 4));
+            }
+
+            let mut piles = [0; 12];
+            for &package in &packages {
+                piles[rng.gen_range(0..12)] += package;
+            }
+
+            let heaviest = piles.iter().max().unwrap();
+            let target = heaviest + dealt as u64 * 4 + rng.gen_range(1..=10);
+
+            // Topping up two equally heavy piles would need the same package twice.
+            if piles.iter().all(|pile| packages.insert(target - pile)) {
+                return packages.iter().map(|p| format!("{}\n", p)).collect();
+            }
+        }
+    }
+}
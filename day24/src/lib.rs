@@ -0,0 +1,260 @@
+use std::{collections::HashSet};
+
+use anyhow::{bail, Context};
+use aoc_common::{parse::parse_lines, Solution};
+use itertools::Itertools;
+
+pub mod generate;
+
+aoc_common::options! {
+    pub struct Options {
+        /// Groups the packages are split into in part one.
+        part_one_groups: u64 = 3,
+        /// Groups the packages are split into in part two, with the trunk.
+        part_two_groups: u64 = 4,
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = HashSet<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type Options = Options;
+
+    fn parse(input: &str) -> anyhow::Result<HashSet<u64>> {
+        Ok(parse_lines(input, str::parse)?.into_iter().collect())
+    }
+
+    fn part_one(packages: &HashSet<u64>, options: &Options) -> anyhow::Result<u64> {
+        balance(packages, options.part_one_groups)
+    }
+
+    fn part_two(packages: &HashSet<u64>, options: &Options) -> anyhow::Result<u64> {
+        balance(packages, options.part_two_groups)
+    }
+}
+
+/// The quantum entanglement of the first group when the packages are split into `groups`
+/// groups of the same weight, or an error if they can't be.
+fn balance(packages: &HashSet<u64>, groups: u64) -> anyhow::Result<u64> {
+    let sum: u64 = packages.iter().sum();
+    if groups < 2 {
+        bail!(
+            "Packages must be split into at least 2 groups, not {}",
+            groups
+        );
+    }
+    if !sum.is_multiple_of(groups) {
+        bail!(
+            "Packages weighing {} in total can't be split into {} equal groups",
+            sum,
+            groups
+        );
+    }
+
+    find_quantum_entanglement(packages, groups)
+        .with_context(|| format!("No way to split the packages into {} equal groups", groups))
+}
+
+/// The smallest quantum entanglement among the smallest first groups of a split into
+/// `partitions` groups of the same weight, if there is such a split.
+pub fn find_quantum_entanglement(elements: &HashSet<u64>, partitions: u64) -> Option<u64> {
+    let sum: u64 = elements.iter().sum();
+    let partition_size: u64 = sum / partitions;
+
+    for group_size in 1..=elements.len() {
+        let mut suspicious_combinations: Vec<Vec<&u64>> = elements.iter().combinations(group_size).filter(|c| c.iter().copied().sum::<u64>() == partition_size).collect();
+
+        suspicious_combinations.sort_by(|a, b| {
+            quantum_entanglement(a).cmp(&quantum_entanglement(b))
+        });
+
+        for c in suspicious_combinations {
+            let mut test_set = elements.clone();
+            for el in &c {
+                test_set.remove(el);
+            }
+
+            if can_partition_n(&test_set, (partitions - 1) as usize) {
+                return Some(quantum_entanglement(&c));
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Whether `set` can be split into `n` groups of the same weight.
+pub fn can_partition_n(set: &HashSet<u64>, n: usize) -> bool {
+    let sum: u64 = set.iter().sum();
+    if !sum.is_multiple_of(n as u64) {
+        return false;
+    }
+    let target_sum = sum / n as u64;
+
+    // Placing the heaviest packages first prunes dead ends early.
+    let mut elements: Vec<u64> = set.iter().copied().collect();
+    elements.sort_unstable_by(|a, b| b.cmp(a));
+
+    fill_groups(&elements, &mut vec![0; n], target_sum)
+}
+
+fn fill_groups(elements: &[u64], groups: &mut [u64], target_sum: u64) -> bool {
+    let Some((&element, rest)) = elements.split_first() else {
+        return true;
+    };
+
+    for i in 0..groups.len() {
+        // A group as heavy as an earlier one would only repeat the same attempt.
+        if groups[i] + element > target_sum || groups[..i].contains(&groups[i]) {
+            continue;
+        }
+
+        groups[i] += element;
+        if fill_groups(rest, groups, target_sum) {
+            return true;
+        }
+        groups[i] -= element;
+    }
+
+    false
+}
+
+pub fn quantum_entanglement(elements: &[&u64]) -> u64 {
+    elements.iter().fold(1, |acc, el| acc * *el)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseErrors;
+    use proptest::prelude::*;
+
+    /// Tries every way of assigning the elements to `n` groups.
+    fn partitions_exhaustively(elements: &[u64], n: usize) -> bool {
+        let assignments = n.pow(elements.len() as u32);
+
+        (0..assignments).any(|mut assignment| {
+            let mut groups = vec![0; n];
+            for element in elements {
+                groups[assignment % n] += element;
+                assignment /= n;
+            }
+            groups.iter().all(|&group| group == groups[0])
+        })
+    }
+
+    #[test]
+    fn test_can_partition_n() {
+        let set: HashSet<u64> = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11].iter().copied().collect();
+        assert!(can_partition_n(&set, 3));
+        assert!(can_partition_n(&set, 4));
+
+        // There is a group of 2, but no three of them.
+        let set: HashSet<u64> = [2, 4].iter().copied().collect();
+        assert!(!can_partition_n(&set, 3));
+    }
+
+    #[test]
+    fn test_find_quantum_entanglement() {
+        let set: HashSet<u64> = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11].iter().copied().collect();
+        assert_eq!(find_quantum_entanglement(&set, 3), Some(99));
+        assert_eq!(find_quantum_entanglement(&set, 4), Some(44));
+
+        let set: HashSet<u64> = [2, 4].iter().copied().collect();
+        assert_eq!(find_quantum_entanglement(&set, 3), None);
+    }
+
+    #[test]
+    fn test_balance_rejects_impossible_groups() {
+        let set: HashSet<u64> = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11].iter().copied().collect();
+        assert!(balance(&set, 0).is_err());
+        assert!(balance(&set, 1).is_err());
+        assert!(balance(&set, 7).is_err());
+        assert_eq!(balance(&set, 3).unwrap(), 99);
+
+        let set: HashSet<u64> = [2, 4].iter().copied().collect();
+        assert!(balance(&set, 3).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let set: HashSet<u64> = [1, 2, 3].iter().copied().collect();
+        assert_eq!(Day24::parse("1\n2\n\n3\n").unwrap(), set);
+
+        let errors = Day24::parse("1\n2\nx3\n3\n").unwrap_err();
+        let errors = errors.downcast::<ParseErrors>().unwrap();
+        assert_eq!(
+            errors
+                .0
+                .iter()
+                .map(|e| (e.line, e.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(3, "x3")]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_can_partition_n_matches_exhaustive_search(
+            set in prop::collection::hash_set(1..40u64, 1..9),
+            n in 1..5usize,
+        ) {
+            let elements: Vec<u64> = set.iter().copied().collect();
+            prop_assert_eq!(can_partition_n(&set, n), partitions_exhaustively(&elements, n));
+        }
+    }
+}
+
+// Advent of Code
+
+//     [About][Events][Shop][Settings][Log Out]
+
+// Pan-Bubr 46*
+//    sub y{2015}
+
+//     [Calendar][AoC++][Sponsors][Leaderboard][Stats]
+
+// --- Day 24: It Hangs in the Balance ---
+
+// It's Christmas Eve, and Santa is loading up the sleigh for this year's deliveries. However, there's one small problem: he can't get the sleigh to balance. If it isn't balanced, he can't defy physics, and nobody gets presents this year.
+
+// No pressure.
+
+// Santa has provided you a list of the weights of every package he needs to fit on the sleigh. The packages need to be split into three groups of exactly the same weight, and every package has to fit. The first group goes in the passenger compartment of the sleigh, and the second and third go in containers on either side. Only when all three groups weigh exactly the same amount will the sleigh be able to fly. Defying physics has rules, you know!
+
+// Of course, that's not the only problem. The first group - the one going in the passenger compartment - needs as few packages as possible so that Santa has some legroom left over. It doesn't matter how many packages are in either of the other two groups, so long as all of the groups weigh the same.
+
+// Furthermore, Santa tells you, if there are multiple ways to arrange the packages such that the fewest possible are in the first group, you need to choose the way where the first group has the smallest quantum entanglement to reduce the chance of any "complications". The quantum entanglement of a group of packages is the product of their weights, that is, the value you get when you multiply their weights together. Only consider quantum entanglement if the first group has the fewest possible number of packages in it and all groups weigh the same amount.
+
+// For example, suppose you have ten packages with weights 1 through 5 and 7 through 11. For this situation, some of the unique first groups, their quantum entanglements, and a way to divide the remaining packages are as follows:
+
+// Group 1;             Group 2; Group 3
+// 11 9       (QE= 99); 10 8 2;  7 5 4 3 1
+// 10 9 1     (QE= 90); 11 7 2;  8 5 4 3
+// 10 8 2     (QE=160); 11 9;    7 5 4 3 1
+// 10 7 3     (QE=210); 11 9;    8 5 4 2 1
+// 10 5 4 1   (QE=200); 11 9;    8 7 3 2
+// 10 5 3 2   (QE=300); 11 9;    8 7 4 1
+// 10 4 3 2 1 (QE=240); 11 9;    8 7 5
+// 9 8 3      (QE=216); 11 7 2;  10 5 4 1
+// 9 7 4      (QE=252); 11 8 1;  10 5 3 2
+// 9 5 4 2    (QE=360); 11 8 1;  10 7 3
+// 8 7 5      (QE=280); 11 9;    10 4 3 2 1
+// 8 5 4 3    (QE=480); 11 9;    10 7 2 1
+// 7 5 4 3 1  (QE=420); 11 9;    10 8 2
+
+// Of these, although 10 9 1 has the smallest quantum entanglement (90), the configuration with only two packages, 11 9, in the passenger compartment gives Santa the most legroom and wins. In this situation, the quantum entanglement for the ideal configuration is therefore 99. Had there been two configurations with only two packages in the first group, the one with the smaller quantum entanglement would be chosen.
+
+// What is the quantum entanglement of the first group of packages in the ideal configuration?
+
+// To begin, get your puzzle input.
+
+// Answer:
+
+// You can also [Shareon Twitter Mastodon] this puzzle.
@@ -0,0 +1,20 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day25;
+
+impl Generate for Day25 {
+    const DEFAULT_SIZE: usize = 3000;
+
+    /// `size` is the largest row and column the code can be at.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let size = size.max(1) as u64;
+
+        format!(
+            "To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.\n",
+            rng.gen_range(1..=size),
+            rng.gen_range(1..=size)
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::bail;
+use aoc_common::Solution;
+
+pub mod generate;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = (u64, u64);
+    type PartOne = u64;
+    type PartTwo = &'static str;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<(u64, u64)> {
+        let numbers = input
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        match numbers[..] {
+            [row, column] => Ok((row, column)),
+            _ => bail!("Expected a row and a column, found {:?}", numbers),
+        }
+    }
+
+    fn part_one(&coordinates: &(u64, u64), _: &()) -> anyhow::Result<u64> {
+        let mut code: u64 = 20151125;
+
+        for _ in 1..decifer_coordinates(coordinates) {
+            code = code * 252533 % 33554393;
+        }
+
+        Ok(code)
+    }
+
+    /// There is no second puzzle on Christmas Day.
+    fn part_two(_: &(u64, u64), _: &()) -> anyhow::Result<&'static str> {
+        Ok("Merry Christmas!")
+    }
+}
+
+pub fn decifer_coordinates((row, column): (u64, u64)) -> u64 {
+    let mut result = 1;
+
+    for x in 1..row {
+        result += x
+    }
+
+    for y in 1..column {
+        result += y + row
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Walks the diagonals of the code table in the order they are filled in.
+    fn walk_table((row, column): (u64, u64)) -> u64 {
+        let mut index = 0;
+
+        for diagonal in 1.. {
+            for r in (1..=diagonal).rev() {
+                index += 1;
+                if (r, diagonal + 1 - r) == (row, column) {
+                    return index;
+                }
+            }
+        }
+
+        unreachable!()
+    }
+
+    #[test]
+    fn test_decifer_coordinates() {
+        assert_eq!(decifer_coordinates((1, 1)), 1);
+        assert_eq!(decifer_coordinates((4, 2)), 12);
+        assert_eq!(decifer_coordinates((1, 6)), 21);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_decifer_coordinates_matches_table_walk(row in 1..200u64, column in 1..200u64) {
+            prop_assert_eq!(decifer_coordinates((row, column)), walk_table((row, column)));
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::{generate::rng, Generate};
+use rand::seq::SliceRandom;
+
+use crate::Day3;
+
+impl Generate for Day3 {
+    const DEFAULT_SIZE: usize = 8192;
+
+    /// `size` is the number of moves.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| *['^', 'v', '<', '>'].choose(&mut rng).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write,
+    num::NonZeroUsize,
+};
+
+use aoc_common::ParseError;
+
+use crate::walk;
+
+/// Who delivered to a house, and how much.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HouseVisits {
+    pub presents: u32,
+    /// Indices of the deliverers who stopped here, in turn order.
+    pub deliverers: BTreeSet<usize>,
+}
+
+/// The smallest rectangle holding every visited house.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y) as usize + 1
+    }
+}
+
+/// Presents delivered to every house along the route, by any number of deliverers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    houses: HashMap<(i32, i32), HouseVisits>,
+}
+
+impl Heatmap {
+    /// Follows the moves like [`deliver_with`](crate::deliver_with), counting every present.
+    pub fn new(input: &str, deliverers: NonZeroUsize) -> Result<Heatmap, ParseError> {
+        let mut houses: HashMap<(i32, i32), HouseVisits> = HashMap::new();
+        walk(input, deliverers, |deliverer, house| {
+            let visits = houses.entry(house).or_default();
+            visits.presents += 1;
+            visits.deliverers.insert(deliverer);
+        })?;
+
+        Ok(Heatmap { houses })
+    }
+
+    pub fn get(&self, house: (i32, i32)) -> Option<&HouseVisits> {
+        self.houses.get(&house)
+    }
+
+    /// Every visited house, in no particular order.
+    pub fn houses(&self) -> impl Iterator<Item = ((i32, i32), &HouseVisits)> {
+        self.houses.iter().map(|(&house, visits)| (house, visits))
+    }
+
+    /// Number of houses with at least one present.
+    pub fn visited(&self) -> usize {
+        self.houses.len()
+    }
+
+    /// The `n` houses with the most presents, most first; ties are ordered by position.
+    pub fn most_visited(&self, n: usize) -> Vec<((i32, i32), u32)> {
+        let mut houses: Vec<((i32, i32), u32)> = self
+            .houses
+            .iter()
+            .map(|(&house, visits)| (house, visits.presents))
+            .collect();
+        houses.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        houses.truncate(n);
+        houses
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        // The origin is always visited, so there is at least one house.
+        let mut bounds = Bounds {
+            min_x: 0,
+            max_x: 0,
+            min_y: 0,
+            max_y: 0,
+        };
+        for &(x, y) in self.houses.keys() {
+            bounds.min_x = bounds.min_x.min(x);
+            bounds.max_x = bounds.max_x.max(x);
+            bounds.min_y = bounds.min_y.min(y);
+            bounds.max_y = bounds.max_y.max(y);
+        }
+        bounds
+    }
+
+    /// A binary PPM image with one pixel per house, north up; unvisited houses are black.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let bounds = self.bounds();
+        let (width, height) = (bounds.width(), bounds.height());
+
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        let header = image.len();
+        image.resize(header + width * height * 3, 0);
+
+        let most = self.most_presents();
+        for (&house, visits) in &self.houses {
+            let (column, row) = pixel(&bounds, house);
+            let offset = header + (row * width + column) * 3;
+            image[offset..offset + 3].copy_from_slice(&colour(visits.presents, most));
+        }
+
+        image
+    }
+
+    /// An SVG image with a square per visited house, north up, titled with its presents.
+    pub fn to_svg(&self) -> String {
+        let bounds = self.bounds();
+        let mut houses: Vec<_> = self.houses.iter().collect();
+        houses.sort_by_key(|&(&house, _)| house);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            bounds.width(),
+            bounds.height()
+        );
+        svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"black\"/>\n");
+
+        let most = self.most_presents();
+        for (&house, visits) in houses {
+            let (column, row) = pixel(&bounds, house);
+            let [r, g, b] = colour(visits.presents, most);
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\">\
+                 <title>({}, {}): {}</title></rect>",
+                column, row, r, g, b, house.0, house.1, visits.presents
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn most_presents(&self) -> u32 {
+        self.houses.values().map(|v| v.presents).max().unwrap_or(1)
+    }
+}
+
+/// Black body colours, from dark red for a single present to white for the busiest
+/// house, on a log scale as a few houses get far more presents than the rest.
+fn colour(presents: u32, most: u32) -> [u8; 3] {
+    let heat = (1.0 + (presents as f64).ln()) / (1.0 + (most as f64).ln());
+
+    let channel = |from: f64| ((heat * 3.0 - from).clamp(0.0, 1.0) * 255.0).round() as u8;
+    [channel(0.0), channel(1.0), channel(2.0)]
+}
+
+/// Column and row of a house in an image of `bounds`, with north at the top.
+fn pixel(bounds: &Bounds, (x, y): (i32, i32)) -> (usize, usize) {
+    ((x - bounds.min_x) as usize, (bounds.max_y - y) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heatmap(input: &str, deliverers: usize) -> Heatmap {
+        Heatmap::new(input, NonZeroUsize::new(deliverers).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_visits() {
+        let heatmap = heatmap("^v^v>", 2);
+
+        assert_eq!(heatmap.visited(), 6);
+        assert_eq!(
+            heatmap.get((0, 0)),
+            Some(&HouseVisits {
+                presents: 2,
+                deliverers: [0, 1].iter().copied().collect()
+            })
+        );
+        assert_eq!(heatmap.get((0, 2)).unwrap().presents, 1);
+        assert_eq!(heatmap.get((0, -2)).unwrap().deliverers.len(), 1);
+        assert_eq!(heatmap.get((5, 5)), None);
+    }
+
+    #[test]
+    fn test_most_visited() {
+        let heatmap = heatmap("^v^v^v<", 1);
+
+        assert_eq!(heatmap.most_visited(2), vec![((0, 0), 4), ((0, 1), 3)]);
+        assert_eq!(heatmap.most_visited(10).len(), 3);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds = heatmap(">>^<<<<vvv", 1).bounds();
+
+        assert_eq!(
+            bounds,
+            Bounds {
+                min_x: -2,
+                max_x: 2,
+                min_y: -2,
+                max_y: 1
+            }
+        );
+        assert_eq!((bounds.width(), bounds.height()), (5, 4));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = heatmap("^>>v^", 1).to_ppm();
+        let header = b"P6\n3 2\n255\n";
+
+        assert_eq!(&ppm[..header.len()], header);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 3 * 2 * 3);
+        // The busiest house, north east of the origin, is white.
+        assert_eq!(&pixels[3 * 2..3 * 3], &[255, 255, 255]);
+        // Houses with a single present are cooler.
+        assert_eq!(&pixels[3 * 3..3 * 4], &[255, 197, 0]);
+        // The house east of the origin was never visited.
+        assert_eq!(&pixels[3 * 4..3 * 5], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = heatmap("^^", 1).to_svg();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("viewBox=\"0 0 1 3\""));
+        assert_eq!(svg.matches("<title>").count(), 3);
+        assert!(svg.contains("<rect x=\"0\" y=\"2\" width=\"1\" height=\"1\" fill=\"#ffffff\"><title>(0, 0): 1</title></rect>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
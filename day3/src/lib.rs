@@ -0,0 +1,203 @@
+use std::{collections::HashSet, num::NonZeroUsize};
+
+use aoc_common::{ParseError, Solution};
+
+pub mod generate;
+pub mod heatmap;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<&str> {
+        let input = input.trim_end();
+        check_moves(input)?;
+        Ok(input)
+    }
+
+    fn part_one(input: &&str, _: &()) -> anyhow::Result<usize> {
+        Ok(deliver(input)?)
+    }
+
+    fn part_two(input: &&str, _: &()) -> anyhow::Result<usize> {
+        Ok(double_deliver(input)?)
+    }
+}
+
+/// Fails on the first character that is not one of `^v<>`, reporting its offset as the
+/// column.
+pub fn check_moves(input: &str) -> Result<(), ParseError> {
+    match input
+        .chars()
+        .position(|c| !matches!(c, '^' | 'v' | '<' | '>'))
+    {
+        Some(offset) => Err(ParseError {
+            line: 1,
+            column: offset + 1,
+            expected: ["\"<\"", "\">\"", "\"^\"", "\"v\""]
+                .iter()
+                .map(|token| token.to_string())
+                .collect(),
+            text: input.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+pub fn deliver(input: &str) -> Result<usize, ParseError> {
+    Ok(deliver_with(input, NonZeroUsize::new(1).unwrap())?.len())
+}
+
+pub fn double_deliver(input: &str) -> Result<usize, ParseError> {
+    Ok(deliver_with(input, NonZeroUsize::new(2).unwrap())?.len())
+}
+
+/// Houses visited when `deliverers` all start at the origin and take turns following
+/// the moves, the first deliverer taking the first move.
+pub fn deliver_with(
+    input: &str,
+    deliverers: NonZeroUsize,
+) -> Result<HashSet<(i32, i32)>, ParseError> {
+    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+    walk(input, deliverers, |_, house| {
+        visited.insert(house);
+    })?;
+
+    Ok(visited)
+}
+
+/// Calls `visit` with the deliverer and house for every present delivered: one by each
+/// deliverer at the origin, then one per move.
+///
+/// Nothing is visited if the input has a character that is not a move.
+pub fn walk(
+    input: &str,
+    deliverers: NonZeroUsize,
+    mut visit: impl FnMut(usize, (i32, i32)),
+) -> Result<(), ParseError> {
+    check_moves(input)?;
+
+    let deliverers = deliverers.get();
+    let mut positions = vec![(0, 0); deliverers];
+    for deliverer in 0..deliverers {
+        visit(deliverer, (0, 0));
+    }
+
+    for (i, c) in input.chars().enumerate() {
+        let deliverer = i % deliverers;
+        let (x, y) = positions[deliverer];
+        let new_position = match c {
+            '>' => (x + 1, y),
+            '^' => (x, y + 1),
+            '<' => (x - 1, y),
+            // `v`, the only move left once they have been checked.
+            _ => (x, y - 1),
+        };
+        visit(deliverer, new_position);
+        positions[deliverer] = new_position;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deliverers(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn test_deliver() {
+        assert_eq!(deliver(">"), Ok(2));
+        assert_eq!(deliver("^>v<"), Ok(4));
+        assert_eq!(deliver("^v^v^v^v^v"), Ok(2));
+    }
+
+    #[test]
+    fn test_double_deliver() {
+        assert_eq!(double_deliver("^v"), Ok(3));
+        assert_eq!(double_deliver("^>v<"), Ok(3));
+        assert_eq!(double_deliver("^v^v^v^v^v"), Ok(11));
+        // Santa's last move must not be dropped when he has one more than the robot.
+        assert_eq!(double_deliver("^v^"), Ok(4));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Day3::parse("^>v<\n").unwrap(), "^>v<");
+
+        let error = Day3::parse("^>x<").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert!(Day3::parse("^>\nv<").is_err());
+    }
+
+    #[test]
+    fn test_deliver_with() {
+        assert_eq!(
+            deliver_with("^>v<", deliverers(3)).unwrap(),
+            [(0, 0), (0, 1), (1, 0), (0, -1), (-1, 1)]
+                .iter()
+                .copied()
+                .collect()
+        );
+        assert_eq!(
+            deliver_with("", deliverers(4)).unwrap(),
+            [(0, 0)].iter().copied().collect()
+        );
+        assert_eq!(deliver_with(">>>>", deliverers(4)).unwrap().len(), 2);
+        assert_eq!(deliver_with(">>>>", deliverers(1)).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_walk_rejects_bad_moves() {
+        let mut visits = 0;
+        let error = walk("^>v\n<", deliverers(2), |_, _| visits += 1).unwrap_err();
+
+        assert_eq!(error.column, 4);
+        assert_eq!(visits, 0);
+        assert!(deliver("^x").is_err());
+    }
+}
+
+// --- Day 3: Perfectly Spherical Houses in a Vacuum ---
+
+// Santa is delivering presents to an infinite two-dimensional grid of houses.
+
+// He begins by delivering a present to the house at his starting location,
+// and then an elf at the North Pole calls him via radio and tells him where to move next.
+// Moves are always exactly one house to the north (^), south (v), east (>), or west (<).
+// After each move, he delivers another present to the house at his new location.
+
+// However, the elf back at the north pole has had a little too much eggnog, and so his directions are a little off,
+// and Santa ends up visiting some houses more than once. How many houses receive at least one present?
+
+// For example:
+
+//     > delivers presents to 2 houses: one at the starting location, and one to the east.
+//     ^>v< delivers presents to 4 houses in a square, including twice to the house at his starting/ending location.
+//     ^v^v^v^v^v delivers a bunch of presents to some very lucky children at only 2 houses.
+
+// --- Part Two ---
+
+// The next year, to speed up the process, Santa creates a robot version of himself, Robo-Santa, to deliver presents with him.
+
+// Santa and Robo-Santa start at the same location (delivering two presents to the same starting house),
+// then take turns moving based on instructions from the elf, who is eggnoggedly reading from the same script as the previous year.
+
+// This year, how many houses receive at least one present?
+
+// For example:
+
+//     ^v delivers presents to 3 houses, because Santa goes north, and then Robo-Santa goes south.
+//     ^>v< now delivers presents to 3 houses, and Santa and Robo-Santa end up back where they started.
+//     ^v^v^v^v^v now delivers presents to 11 houses, with Santa going one direction and Robo-Santa going the other.
@@ -0,0 +1,15 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day4;
+
+impl Generate for Day4 {
+    const DEFAULT_SIZE: usize = 8;
+
+    /// `size` is the length of the secret key. Mining takes about as long whatever it is.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size).map(|_| rng.gen_range('a'..='z')).collect()
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fmt,
+    ops::Range,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use anyhow::{bail, Context};
+use aoc_common::Solution;
+
+pub mod generate;
+pub mod resume;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = &'a str;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<&str> {
+        Ok(input.trim())
+    }
+
+    fn part_one(input: &&str, _: &()) -> anyhow::Result<u64> {
+        Ok(mine(input, &Target::leading_zeroes(5), None)
+            .context("No nonce gives five leading zeroes")?
+            .nonce)
+    }
+
+    fn part_two(input: &&str, _: &()) -> anyhow::Result<u64> {
+        Ok(mine(input, &Target::leading_zeroes(6), None)
+            .context("No nonce gives six leading zeroes")?
+            .nonce)
+    }
+}
+
+/// The lowest nonce in `nonces`, or in all of them, whose digest hits `target`.
+pub fn mine(seed: &str, target: &Target, nonces: Option<Range<u64>>) -> Option<Mined> {
+    Miner::new(seed).mine(target, nonces.unwrap_or(0..u64::MAX))
+}
+
+/// The hex digits a digest has to start with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    nibbles: Vec<u8>,
+}
+
+impl Target {
+    /// At least `zeroes` leading zero hex digits, like the puzzle's five and six.
+    ///
+    /// Panics if there are more zeroes than an MD5 digest has digits.
+    pub fn leading_zeroes(zeroes: usize) -> Target {
+        assert!(zeroes <= 32, "An MD5 digest only has 32 hex digits");
+        Target {
+            nibbles: vec![0; zeroes],
+        }
+    }
+
+    /// A hex digest starting with `prefix`, in either case.
+    pub fn hex_prefix(prefix: &str) -> anyhow::Result<Target> {
+        let nibbles = prefix
+            .chars()
+            .map(|c| match c.to_digit(16) {
+                Some(digit) => Ok(digit as u8),
+                None => bail!("Invalid hex digit {:?} in target {:?}", c, prefix),
+            })
+            .collect::<anyhow::Result<Vec<u8>>>()?;
+
+        if nibbles.len() > 32 {
+            bail!("Target {:?} is longer than an MD5 digest", prefix);
+        }
+
+        Ok(Target { nibbles })
+    }
+
+    pub fn matches(&self, digest: &md5::Digest) -> bool {
+        self.nibbles.iter().enumerate().all(|(i, &nibble)| {
+            let byte = digest[i / 2];
+            let digit = if i % 2 == 0 { byte >> 4 } else { byte & 0xf };
+            digit == nibble
+        })
+    }
+}
+
+/// The hex digits, in lower case.
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for nibble in &self.nibbles {
+            write!(f, "{:x}", nibble)?;
+        }
+        Ok(())
+    }
+}
+
+/// A nonce that hit the target, with its digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mined {
+    pub nonce: u64,
+    pub digest: md5::Digest,
+}
+
+/// Nonces handed to a worker at a time.
+const BATCH_SIZE: u64 = 4096;
+
+/// Searches for the lowest nonce whose hash, appended to a secret key, satisfies a
+/// predicate, splitting the nonces across worker threads.
+///
+/// The MD5 state after the key is computed once and cloned for every nonce.
+#[derive(Clone)]
+pub struct Miner {
+    prefix: md5::Context,
+    threads: usize,
+}
+
+impl Miner {
+    /// A miner for `seed` using every available CPU.
+    pub fn new(seed: &str) -> Miner {
+        let mut prefix = md5::Context::new();
+        prefix.consume(seed);
+
+        Miner {
+            prefix,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    pub fn with_threads(self, threads: usize) -> Miner {
+        Miner {
+            threads: threads.max(1),
+            ..self
+        }
+    }
+
+    pub fn digest(&self, nonce: u64) -> md5::Digest {
+        let mut context = self.prefix.clone();
+        context.consume(decimal(nonce, &mut [0; 20]));
+        context.compute()
+    }
+
+    /// The lowest nonce in `nonces` whose digest hits `target`.
+    pub fn mine(&self, target: &Target, nonces: Range<u64>) -> Option<Mined> {
+        self.find(|digest| target.matches(digest), nonces)
+            .map(|nonce| Mined {
+                nonce,
+                digest: self.digest(nonce),
+            })
+    }
+
+    /// The lowest nonce in `nonces` whose digest satisfies `predicate`, whatever the
+    /// number of threads.
+    ///
+    /// Workers claim batches of nonces in increasing order and stop claiming once a batch
+    /// starts past the best nonce found, so every lower nonce has been tried.
+    pub fn find<P>(&self, predicate: P, nonces: Range<u64>) -> Option<u64>
+    where
+        P: Fn(&md5::Digest) -> bool + Sync,
+    {
+        let next_batch = AtomicU64::new(0);
+        // The end of the range is never a candidate, so it can stand for nothing found.
+        let found = AtomicU64::new(nonces.end);
+
+        let work = || loop {
+            let start = next_batch
+                .fetch_add(1, Ordering::Relaxed)
+                .checked_mul(BATCH_SIZE)
+                .and_then(|offset| nonces.start.checked_add(offset));
+            let start = match start {
+                Some(start) if start < nonces.end && start < found.load(Ordering::Relaxed) => start,
+                _ => return,
+            };
+
+            let mut batch = start..nonces.end.min(start.saturating_add(BATCH_SIZE));
+            if let Some(nonce) = batch.find(|&nonce| predicate(&self.digest(nonce))) {
+                found.fetch_min(nonce, Ordering::Relaxed);
+            }
+        };
+
+        thread::scope(|scope| {
+            for _ in 1..self.threads {
+                scope.spawn(work);
+            }
+            work();
+        });
+
+        Some(found.into_inner()).filter(|&nonce| nonce < nonces.end)
+    }
+}
+
+/// Writes `n` in decimal to the end of `buffer`, returning the digits.
+fn decimal(mut n: u64, buffer: &mut [u8; 20]) -> &[u8] {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return &buffer[start..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nonce(seed: &str, zeroes: usize) -> Option<u64> {
+        mine(seed, &Target::leading_zeroes(zeroes), None).map(|mined| mined.nonce)
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(nonce("abcdef", 5), Some(609043));
+        assert_eq!(nonce("pqrstuv", 5), Some(1048970));
+
+        assert_eq!(nonce("abcdef", 6), Some(6742839));
+        assert_eq!(nonce("pqrstuv", 6), Some(5714438));
+    }
+
+    #[test]
+    fn test_mine() {
+        let mined = mine("abcdef", &Target::leading_zeroes(5), None).unwrap();
+        assert_eq!(
+            format!("{:x}", mined.digest),
+            "000001dbbfa3a5c83a2d506429c7b00e"
+        );
+
+        assert_eq!(nonce("abcdef", 0), Some(0));
+
+        let target = Target::hex_prefix("000001DB").unwrap();
+        assert_eq!(mine("abcdef", &target, Some(0..609043)), None);
+        assert_eq!(mine("abcdef", &target, Some(609043..609044)), Some(mined));
+        assert_eq!(mine("abcdef", &target, Some(500_000..700_000)), Some(mined));
+    }
+
+    #[test]
+    fn test_target() {
+        let digest = md5::compute("abcdef609043");
+
+        assert!(Target::leading_zeroes(5).matches(&digest));
+        assert!(!Target::leading_zeroes(6).matches(&digest));
+        assert!(Target::hex_prefix("000001d").unwrap().matches(&digest));
+        assert!(!Target::hex_prefix("000001c").unwrap().matches(&digest));
+        assert!(Target::hex_prefix("").unwrap().matches(&digest));
+
+        assert_eq!(Target::hex_prefix("00aB").unwrap().to_string(), "00ab");
+
+        assert!(Target::hex_prefix("00g").is_err());
+        assert!(Target::hex_prefix(&"0".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_threads_agree() {
+        let five_zeroes = |hash: &md5::Digest| hash[0] == 0 && hash[1] == 0 && hash[2] <= 15;
+
+        for threads in 1..=4 {
+            let miner = Miner::new("abcdef").with_threads(threads);
+            assert_eq!(miner.find(five_zeroes, 0..u64::MAX), Some(609043));
+        }
+        // Every batch has matches; the lowest must beat those found by the other workers.
+        let sequential = (0..).find(|n| md5::compute(format!("abcdef{}", n))[0] == 0);
+        let zero_byte = |hash: &md5::Digest| hash[0] == 0;
+        for threads in 1..=4 {
+            let miner = Miner::new("abcdef").with_threads(threads);
+            assert_eq!(miner.find(zero_byte, 0..u64::MAX), sequential);
+        }
+    }
+
+    #[test]
+    fn test_digest() {
+        assert_eq!(
+            Miner::new("abcdef").digest(609043).0,
+            md5::compute("abcdef609043").0
+        );
+        assert_eq!(decimal(0, &mut [0; 20]), b"0");
+        assert_eq!(
+            decimal(u64::MAX, &mut [0; 20]),
+            u64::MAX.to_string().as_bytes()
+        );
+    }
+
+}
+
+// --- Day 4: The Ideal Stocking Stuffer ---
+
+// Santa needs help mining some AdventCoins (very similar to bitcoins) to use as gifts
+//  for all the economically forward-thinking little girls and boys.
+
+// To do this, he needs to find MD5 hashes which, in hexadecimal, start with at least five zeroes. 
+// The input to the MD5 hash is some secret key (your puzzle input, given below) followed by a number in decimal. 
+// To mine AdventCoins, you must find Santa the lowest positive number (no leading zeroes: 1, 2, 3, ...) that produces such a hash.
+
+// For example:
+
+//     If your secret key is abcdef, the answer is 609043, because the MD5 hash of abcdef609043 starts with five zeroes (000001dbbfa...), 
+//     and it is the lowest such number to do so.
+//     If your secret key is pqrstuv, the lowest number it combines with to make an MD5 hash starting with five zeroes is 1048970; 
+//     that is, the MD5 hash of pqrstuv1048970 looks like 000006136ef....
+
+// Your puzzle input is bgvyzdsv.
\ No newline at end of file
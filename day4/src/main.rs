@@ -1,52 +1,95 @@
-fn main() {
-    let s = "bgvyzdsv";
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
-    println!("[Part one]: {}", hash(s, false));
-    println!("[Part two]: {}", hash(s, true));
-}
+use aoc_common::{input::Source, solve, Solution};
+use clap::{ArgGroup, Parser};
+use day4::{
+    resume::{Outcome, Search},
+    Day4, Target,
+};
 
-fn hash(seed: &str, harder: bool) -> i32 {
-    for i in 0.. {
-        let test_string = [String::from(seed), i.to_string()].join("");
-        let hash = md5::compute(test_string);
+/// Day 4: The Ideal Stocking Stuffer.
+#[derive(Parser)]
+#[command(group(ArgGroup::new("targets").args(["zeroes", "target"])))]
+struct Cli {
+    /// Input file, or `-` to read it from stdin [default: the embedded input]
+    input: Option<Source>,
 
-        if hash[0] == 0 && hash[1] == 0 && hash[2] <= if harder { 0 } else { 15 } {
-            return i;
-        }
-    }
+    /// Mine for a digest with this many leading zeroes instead of solving
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(0..=32))]
+    zeroes: Option<u8>,
 
-    return 0;
-}
+    /// Mine for a digest starting with these hex digits instead of solving
+    #[arg(long, value_name = "HEX")]
+    target: Option<String>,
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// First nonce to try
+    #[arg(long, default_value_t = 0, requires = "targets")]
+    from: u64,
 
-    #[test]
-    fn test_hash() {
-        assert_eq!(hash("abcdef", false), 609043);
-        assert_eq!(hash("pqrstuv", false), 1048970);
+    /// Stop before this nonce [default: never]
+    #[arg(long, requires = "targets")]
+    to: Option<u64>,
 
-        assert_eq!(hash("abcdef", true), 6742839);
-        assert_eq!(hash("pqrstuv", true), 5714438);
-    }
+    /// Save progress to this file, and resume from it if it exists
+    #[arg(long, value_name = "PATH", requires = "targets")]
+    state: Option<PathBuf>,
 
+    /// Worker threads [default: number of CPUs]
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..), requires = "targets")]
+    jobs: Option<u16>,
 }
 
-// --- Day 4: The Ideal Stocking Stuffer ---
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let input = cli.input.clone().unwrap_or_default().read(Day4::INPUT)?;
+    let seed = Day4::parse(&input)?;
 
-// Santa needs help mining some AdventCoins (very similar to bitcoins) to use as gifts
-//  for all the economically forward-thinking little girls and boys.
+    let target = match (cli.zeroes, &cli.target) {
+        (Some(zeroes), _) => Target::leading_zeroes(zeroes as usize),
+        (None, Some(prefix)) => Target::hex_prefix(prefix)?,
+        (None, None) => {
+            for answer in solve::<Day4>(&input, None, &())? {
+                println!("{}", answer);
+            }
+            return Ok(());
+        }
+    };
 
-// To do this, he needs to find MD5 hashes which, in hexadecimal, start with at least five zeroes. 
-// The input to the MD5 hash is some secret key (your puzzle input, given below) followed by a number in decimal. 
-// To mine AdventCoins, you must find Santa the lowest positive number (no leading zeroes: 1, 2, 3, ...) that produces such a hash.
+    let mut search = Search::new(seed, &target, cli.from..cli.to.unwrap_or(u64::MAX));
+    if let Some(path) = &cli.state {
+        search = search.with_state_file(path);
+    }
+    if let Some(jobs) = cli.jobs {
+        search = search.with_threads(jobs as usize);
+    }
 
-// For example:
+    // The first Ctrl-C stops at the end of the current segment, saving the state.
+    let cancel = Arc::new(AtomicBool::new(false));
+    let handler = Arc::clone(&cancel);
+    ctrlc::set_handler(move || handler.store(true, Ordering::Relaxed))?;
 
-//     If your secret key is abcdef, the answer is 609043, because the MD5 hash of abcdef609043 starts with five zeroes (000001dbbfa...), 
-//     and it is the lowest such number to do so.
-//     If your secret key is pqrstuv, the lowest number it combines with to make an MD5 hash starting with five zeroes is 1048970; 
-//     that is, the MD5 hash of pqrstuv1048970 looks like 000006136ef....
+    let outcome = search.run(
+        |progress| {
+            eprintln!(
+                "Tried nonces up to {}, {:.0} hashes/s",
+                progress.next,
+                progress.hashes_per_second()
+            )
+        },
+        &cancel,
+    )?;
 
-// Your puzzle input is bgvyzdsv.
\ No newline at end of file
+    match outcome {
+        Outcome::Found(mined) => println!("{} {:x}", mined.nonce, mined.digest),
+        Outcome::Exhausted => anyhow::bail!("No nonce in range hits {}", target),
+        Outcome::Cancelled { next } => eprintln!("Cancelled, resume from {}", next),
+    }
+
+    Ok(())
+}
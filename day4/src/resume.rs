@@ -0,0 +1,316 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+
+use crate::{Mined, Miner, Target};
+
+/// Nonces searched between checkpoints, progress reports and checks for cancellation.
+const SEGMENT_SIZE: u64 = 1 << 20;
+
+/// How far a search has got.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Every nonce below this one has been tried, including in earlier sessions.
+    pub next: u64,
+    /// Nonces hashed in this session.
+    pub hashes: u64,
+    /// Time spent in this session.
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    pub fn hashes_per_second(&self) -> f64 {
+        self.hashes as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// How a search ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Found(Mined),
+    /// No nonce in the range hits the target.
+    Exhausted,
+    /// Stopped early; every nonce below `next` has been tried.
+    Cancelled {
+        next: u64,
+    },
+}
+
+/// A search for the lowest nonce hitting a target that reports its progress, can be
+/// cancelled, and picks up where it stopped from a state file.
+///
+/// The search runs in segments of nonces; progress, the state file and cancellation are
+/// all handled between segments.
+pub struct Search<'a> {
+    seed: &'a str,
+    target: &'a Target,
+    nonces: Range<u64>,
+    miner: Miner,
+    state: Option<PathBuf>,
+    segment: u64,
+}
+
+impl<'a> Search<'a> {
+    pub fn new(seed: &'a str, target: &'a Target, nonces: Range<u64>) -> Search<'a> {
+        Search {
+            seed,
+            target,
+            nonces,
+            miner: Miner::new(seed),
+            state: None,
+            segment: SEGMENT_SIZE,
+        }
+    }
+
+    /// Saves the next nonce to try to `path` after every segment, and resumes from it if
+    /// it exists. The file is removed once the search is over.
+    pub fn with_state_file<P: AsRef<Path>>(self, path: P) -> Search<'a> {
+        Search {
+            state: Some(path.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    pub fn with_threads(self, threads: usize) -> Search<'a> {
+        Search {
+            miner: self.miner.with_threads(threads),
+            ..self
+        }
+    }
+
+    pub fn with_segment(self, nonces: u64) -> Search<'a> {
+        Search {
+            segment: nonces.max(1),
+            ..self
+        }
+    }
+
+    /// Searches until a nonce is found, the range runs out or `cancel` is set, calling
+    /// `progress` after every segment.
+    pub fn run(
+        &self,
+        mut progress: impl FnMut(&Progress),
+        cancel: &AtomicBool,
+    ) -> anyhow::Result<Outcome> {
+        let start = Instant::now();
+        let first = self.resume()?;
+        let mut next = first;
+
+        while next < self.nonces.end {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(Outcome::Cancelled { next });
+            }
+
+            let end = self.nonces.end.min(next.saturating_add(self.segment));
+            if let Some(mined) = self.miner.mine(self.target, next..end) {
+                self.finish()?;
+                return Ok(Outcome::Found(mined));
+            }
+
+            next = end;
+            self.save(next)?;
+            progress(&Progress {
+                next,
+                hashes: next - first,
+                elapsed: start.elapsed(),
+            });
+        }
+
+        self.finish()?;
+        Ok(Outcome::Exhausted)
+    }
+
+    /// The nonce to start from, from the state file if there is one for this search.
+    fn resume(&self) -> anyhow::Result<u64> {
+        let path = match &self.state {
+            Some(path) => path,
+            None => return Ok(self.nonces.start),
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(self.nonces.start),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        let state =
+            State::parse(&contents).with_context(|| format!("Invalid {}", path.display()))?;
+        if state.seed != self.seed || state.target != self.target.to_string() {
+            bail!(
+                "{} belongs to a search for {} with key {:?}",
+                path.display(),
+                state.target,
+                state.seed
+            );
+        }
+
+        Ok(state.next.clamp(self.nonces.start, self.nonces.end))
+    }
+
+    fn save(&self, next: u64) -> anyhow::Result<()> {
+        let path = match &self.state {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let state = State {
+            seed: self.seed.to_string(),
+            target: self.target.to_string(),
+            next,
+        };
+
+        // Written next to the state and renamed over it, so a crash never leaves half a file.
+        let partial = path.with_extension("partial");
+        fs::write(&partial, state.to_string())
+            .and_then(|_| fs::rename(&partial, path))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    fn finish(&self) -> anyhow::Result<()> {
+        match &self.state {
+            Some(path) => match fs::remove_file(path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    Err(e).with_context(|| format!("Failed to remove {}", path.display()))
+                }
+                _ => Ok(()),
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+/// What the state file records: the search, and how far it got.
+#[derive(Debug, PartialEq, Eq)]
+struct State {
+    seed: String,
+    target: String,
+    next: u64,
+}
+
+impl State {
+    fn parse(contents: &str) -> anyhow::Result<State> {
+        let mut seed = None;
+        let mut target = None;
+        let mut next = None;
+
+        for (line, text) in aoc_common::input::lines(contents) {
+            if text.starts_with('#') {
+                continue;
+            }
+
+            match text.split_once(' ') {
+                Some(("seed", value)) => seed = Some(value.to_string()),
+                Some(("target", value)) => target = Some(value.to_string()),
+                Some(("next", value)) => {
+                    next = Some(
+                        value
+                            .parse()
+                            .with_context(|| format!("line {}: invalid nonce", line))?,
+                    )
+                }
+                _ => bail!("line {}: expected `seed`, `target` or `next`", line),
+            }
+        }
+
+        Ok(State {
+            seed: seed.context("No seed")?,
+            target: target.context("No target")?,
+            next: next.context("No next nonce")?,
+        })
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day 4 mining state")?;
+        writeln!(f, "seed {}", self.seed)?;
+        writeln!(f, "target {}", self.target)?;
+        writeln!(f, "next {}", self.next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static NEVER: AtomicBool = AtomicBool::new(false);
+
+    #[test]
+    fn test_run() {
+        let target = Target::leading_zeroes(5);
+        let search = Search::new("abcdef", &target, 0..u64::MAX).with_segment(100_000);
+
+        let mut reports = Vec::new();
+        let outcome = search.run(|p| reports.push(p.next), &NEVER).unwrap();
+
+        assert_eq!(
+            outcome,
+            Outcome::Found(crate::mine("abcdef", &target, None).unwrap())
+        );
+        assert_eq!(reports, (1..=6).map(|i| i * 100_000).collect::<Vec<_>>());
+
+        let search = Search::new("abcdef", &target, 0..600_000);
+        assert_eq!(search.run(|_| {}, &NEVER).unwrap(), Outcome::Exhausted);
+    }
+
+    #[test]
+    fn test_cancel_and_resume() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = dir.path().join("day4.state");
+        let target = Target::leading_zeroes(5);
+        let search = Search::new("abcdef", &target, 0..u64::MAX)
+            .with_segment(200_000)
+            .with_state_file(&state);
+
+        let cancel = AtomicBool::new(false);
+        let outcome = search
+            .run(
+                |p| cancel.store(p.next >= 400_000, Ordering::Relaxed),
+                &cancel,
+            )
+            .unwrap();
+        assert_eq!(outcome, Outcome::Cancelled { next: 400_000 });
+        assert_eq!(
+            fs::read_to_string(&state).unwrap(),
+            "# day 4 mining state\nseed abcdef\ntarget 00000\nnext 400000\n"
+        );
+
+        let mut first = None;
+        let outcome = search.run(|p| first = first.or(Some(*p)), &NEVER).unwrap();
+        assert!(matches!(
+            outcome,
+            Outcome::Found(Mined { nonce: 609043, .. })
+        ));
+        assert_eq!(first.map(|p| (p.next, p.hashes)), Some((600_000, 200_000)));
+        assert!(!state.exists());
+    }
+
+    #[test]
+    fn test_other_search_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = dir.path().join("day4.state");
+        fs::write(&state, "seed abcdef\ntarget 000000\nnext 5\n").unwrap();
+
+        let target = Target::leading_zeroes(5);
+        let error = Search::new("abcdef", &target, 0..u64::MAX)
+            .with_state_file(&state)
+            .run(|_| {}, &NEVER)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("belongs to a search for 000000 with key \"abcdef\""));
+
+        fs::write(&state, "seed abcdef\nnext x\n").unwrap();
+        assert!(Search::new("abcdef", &target, 0..u64::MAX)
+            .with_state_file(&state)
+            .run(|_| {}, &NEVER)
+            .is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::{self, Display};
+
+/// What a rule found in a word. Positions count characters from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Evidence {
+    /// Every vowel counted, with its position.
+    Vowels(Vec<(usize, char)>),
+    /// The first letter twice in a row.
+    Double { letter: char, at: usize },
+    /// The first forbidden string in the word.
+    Forbidden { string: String, at: usize },
+    /// The first pair seen again without overlapping, at both of its positions.
+    Pair {
+        pair: String,
+        first: usize,
+        second: usize,
+    },
+    /// The first letter repeated with one letter between.
+    Sandwich { letters: String, at: usize },
+}
+
+impl Display for Evidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Evidence::Vowels(vowels) if vowels.is_empty() => write!(f, "no vowels"),
+            Evidence::Vowels(vowels) => {
+                let found: Vec<String> = vowels
+                    .iter()
+                    .map(|(at, vowel)| format!("{} at {}", vowel, at))
+                    .collect();
+                let plural = if vowels.len() == 1 { "" } else { "s" };
+                write!(f, "{} vowel{}: {}", vowels.len(), plural, found.join(", "))
+            }
+            Evidence::Double { letter, at } => write!(f, "{}{} at {}", letter, letter, at),
+            Evidence::Forbidden { string, at } => write!(f, "{} at {}", string, at),
+            Evidence::Pair {
+                pair,
+                first,
+                second,
+            } => write!(f, "{} at {} and {}", pair, first, second),
+            Evidence::Sandwich { letters, at } => write!(f, "{} at {}", letters, at),
+        }
+    }
+}
+
+/// The outcome of a single rule for a word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    /// The rule, as its spec.
+    pub rule: String,
+    pub passed: bool,
+    /// What the rule found, whether it passed or not; `None` if there was nothing.
+    pub evidence: Option<Evidence>,
+}
+
+/// `pass` or `fail`, the rule, and its evidence.
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = if self.passed { "pass" } else { "fail" };
+        match &self.evidence {
+            Some(evidence) => write!(f, "{} {}: {}", outcome, self.rule, evidence),
+            None => write!(f, "{} {}: nothing found", outcome, self.rule),
+        }
+    }
+}
+
+/// Every rule's check for a word, in rule set order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub word: String,
+    pub checks: Vec<Check>,
+}
+
+impl Explanation {
+    pub fn is_nice(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// The checks that made the word naughty.
+    pub fn failures(&self) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(|check| !check.passed)
+    }
+}
+
+/// The word and its verdict, then every check on its own indented line.
+impl Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.is_nice() { "nice" } else { "naughty" };
+        writeln!(f, "{}: {}", self.word, verdict)?;
+        for check in &self.checks {
+            writeln!(f, "    {}", check)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let explanation = Explanation {
+            word: "aaxy".to_string(),
+            checks: vec![
+                Check {
+                    rule: "vowels>=3".to_string(),
+                    passed: false,
+                    evidence: Some(Evidence::Vowels(vec![(0, 'a'), (1, 'a')])),
+                },
+                Check {
+                    rule: "double".to_string(),
+                    passed: true,
+                    evidence: Some(Evidence::Double { letter: 'a', at: 0 }),
+                },
+                Check {
+                    rule: "pair".to_string(),
+                    passed: false,
+                    evidence: None,
+                },
+            ],
+        };
+
+        assert!(!explanation.is_nice());
+        assert_eq!(explanation.failures().count(), 2);
+        assert_eq!(
+            explanation.to_string(),
+            "aaxy: naughty\n    \
+             fail vowels>=3: 2 vowels: a at 0, a at 1\n    \
+             pass double: aa at 0\n    \
+             fail pair: nothing found\n"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day5;
+
+impl Generate for Day5 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` is the number of strings, each 16 letters long like the real ones.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| {
+                let mut line: String = (0..16).map(|_| rng.gen_range('a'..='z')).collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::Solution;
+
+use crate::rules::RuleSet;
+
+pub mod explain;
+pub mod generate;
+pub mod rules;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(input: &Vec<&str>, _: &()) -> anyhow::Result<usize> {
+        Ok(RuleSet::part_one().count_nice(input.iter().copied()))
+    }
+
+    fn part_two(input: &Vec<&str>, _: &()) -> anyhow::Result<usize> {
+        Ok(RuleSet::part_two().count_nice(input.iter().copied()))
+    }
+}
+
+/// Whether a word is nice under the rules from part one.
+pub fn is_nice_1(input: &str) -> bool {
+    RuleSet::part_one().is_nice(input)
+}
+
+/// Whether a word is nice under the rules from part two.
+pub fn is_nice_2(input: &str) -> bool {
+    RuleSet::part_two().is_nice(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_nice_1() {
+        assert_eq!(is_nice_1("ugknbfddgicrmopn"), true);
+        assert_eq!(is_nice_1("aaa"), true);
+        assert_eq!(is_nice_1("jchzalrnumimnmhp"), false);
+        assert_eq!(is_nice_1("haegwjzuvuyypxyu"), false);
+        assert_eq!(is_nice_1("dvszwmarrgswjxmb"), false);
+    }
+
+    #[test]
+    fn test_is_nice_2() {
+        assert_eq!(is_nice_2("qjhvhtzxzqqjkmpb"), true);
+        assert_eq!(is_nice_2("xxyxx"), true);
+        assert_eq!(is_nice_2("uurcxstgmygtbstg"), false);
+        assert_eq!(is_nice_2("ieodomkazucvgmuy"), false);
+    }
+}
+
+// --- Day 5: Doesn't He Have Intern-Elves For This? ---
+
+// Santa needs help figuring out which strings in his text file are naughty or nice.
+
+// A nice string is one with all of the following properties:
+
+//     It contains at least three vowels (aeiou only), like aei, xazegov, or aeiouaeiouaeiou.
+//     It contains at least one letter that appears twice in a row, like xx, abcdde (dd), or aabbccdd (aa, bb, cc, or dd).
+//     It does not contain the strings ab, cd, pq, or xy, even if they are part of one of the other requirements.
+
+// For example:
+
+//     ugknbfddgicrmopn is nice because it has at least three vowels (u...i...o...), a double letter (...dd...), and none of the disallowed substrings.
+//     aaa is nice because it has at least three vowels and a double letter, even though the letters used by different rules overlap.
+//     jchzalrnumimnmhp is naughty because it has no double letter.
+//     haegwjzuvuyypxyu is naughty because it contains the string xy.
+//     dvszwmarrgswjxmb is naughty because it contains only one vowel.
+
+// How many strings are nice?
+
+// --- Part Two ---
+
+// Realizing the error of his ways, Santa has switched to a better model of determining whether a string is naughty or nice.
+// None of the old rules apply, as they are all clearly ridiculous.
+
+// Now, a nice string is one with all of the following properties:
+
+//     It contains a pair of any two letters that appears at least twice in the string without overlapping, like xyxy (xy) or aabcdefgaa (aa), but not like aaa (aa, but it overlaps).
+//     It contains at least one letter which repeats with exactly one letter between them, like xyx, abcdefeghi (efe), or even aaa.
+
+// For example:
+
+//     qjhvhtzxzqqjkmpb is nice because is has a pair that appears twice (qj) and a letter that repeats with exactly one letter between them (zxz).
+//     xxyxx is nice because it has a pair that appears twice and a letter that repeats with one between, even though the letters used by each rule overlap.
+//     uurcxstgmygtbstg is naughty because it has a pair (tg) but no repeat with a single letter between them.
+//     ieodomkazucvgmuy is naughty because it has a repeating letter with one between (odo), but no pair that appears twice.
+
+// How many strings are nice under these new rules?
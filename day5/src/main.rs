@@ -1,112 +1,62 @@
-fn main() -> anyhow::Result<()> {
-    let s: Vec<(bool, bool)> = include_str!("input.txt")
-        .lines()
-        .map(|line| (is_nice_1(line), is_nice_2(line)))
-        .collect();
-    println!("[Part one]: {}", s.iter().filter(|x| x.0).count());
-    println!("[Part two]: {}", s.iter().filter(|x| x.1).count());
-
-    Ok(())
-}
-
-fn is_nice_1(input: &str) -> bool {
-    let chars: Vec<char> = input.chars().collect();
-    let vowels = chars
-        .iter()
-        .filter(|&c| "aeiou".contains(&c.to_string()))
-        .count();
-    let double = chars.windows(2).any(|c| c[0] == c[1]);
-
-    let forbidden = ["ab", "cd", "pq", "xy"];
-    let contains_forbidden = forbidden.iter().any(|&c| input.contains(&c));
-    return vowels >= 3 && double && !contains_forbidden;
+use anyhow::anyhow;
+use aoc_common::{input::Source, solve, ParseError, Solution};
+use clap::Parser;
+use day5::{
+    rules::{RuleSet, PART_ONE, PART_TWO},
+    Day5,
+};
+
+/// Day 5: Doesn't He Have Intern-Elves For This?
+#[derive(Parser)]
+struct Cli {
+    /// Input file, or `-` to read it from stdin [default: the embedded input]
+    input: Option<Source>,
+
+    /// Count the words nice under these rules instead of solving, e.g. `vowels>=3; double`
+    #[arg(long, value_name = "SPEC")]
+    rules: Option<String>,
+
+    /// Use the rules from this part of the puzzle instead of `--rules` [default: 1]
+    #[arg(
+        short,
+        long,
+        conflicts_with = "rules",
+        value_parser = clap::value_parser!(u8).range(1..=2)
+    )]
+    part: Option<u8>,
+
+    /// Explain every word's verdict rule by rule, under `--rules` or `--part`
+    #[arg(long)]
+    explain: bool,
 }
 
-fn is_nice_2(input: &str) -> bool {
-    let chars: Vec<char> = input.chars().collect();
-    let pairs = chars
-        .windows(2)
-        .map(|c| c.into_iter().collect::<String>())
-        .collect::<Vec<String>>();
-
-    let mut paired = false;
-
-    for (i, my_str) in pairs.clone().iter().enumerate() {
-        let pairs_clone: Vec<String> = pairs
-            .clone()
-            .iter()
-            .enumerate()
-            .filter(|(j, _)| *j != i && *j + 1 != i && *j != i + 1)
-            .map(|(_, s)| s.clone())
-            .collect();
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let input = cli.input.unwrap_or_default().read(Day5::INPUT)?;
 
-        if pairs_clone.iter().any(|pair| pair == my_str) {
-            paired = true;
-            break;
+    if cli.rules.is_none() && cli.part.is_none() && !cli.explain {
+        for answer in solve::<Day5>(&input, None, &())? {
+            println!("{}", answer);
         }
+        return Ok(());
     }
 
-    let overlap = chars.windows(3).any(|c| c[0] == c[2]);
-    return overlap && paired;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_is_nice_1() {
-        assert_eq!(is_nice_1("ugknbfddgicrmopn"), true);
-        assert_eq!(is_nice_1("aaa"), true);
-        assert_eq!(is_nice_1("jchzalrnumimnmhp"), false);
-        assert_eq!(is_nice_1("haegwjzuvuyypxyu"), false);
-        assert_eq!(is_nice_1("dvszwmarrgswjxmb"), false);
+    let spec = match (cli.rules.as_deref(), cli.part) {
+        (Some(spec), _) => spec,
+        (None, Some(2)) => PART_TWO,
+        (None, _) => PART_ONE,
+    };
+    let rules: RuleSet = spec
+        .parse()
+        .map_err(|e: ParseError| anyhow!("Invalid rules, {}", e.report()))?;
+
+    if cli.explain {
+        for word in input.lines() {
+            print!("{}", rules.explain(word));
+        }
+    } else {
+        println!("{}", rules.count_nice(input.lines()));
     }
 
-    #[test]
-    fn test_is_nice_2() {
-        assert_eq!(is_nice_2("qjhvhtzxzqqjkmpb"), true);
-        assert_eq!(is_nice_2("xxyxx"), true);
-        assert_eq!(is_nice_2("uurcxstgmygtbstg"), false);
-        assert_eq!(is_nice_2("ieodomkazucvgmuy"), false);
-    }
+    Ok(())
 }
-
-// --- Day 5: Doesn't He Have Intern-Elves For This? ---
-
-// Santa needs help figuring out which strings in his text file are naughty or nice.
-
-// A nice string is one with all of the following properties:
-
-//     It contains at least three vowels (aeiou only), like aei, xazegov, or aeiouaeiouaeiou.
-//     It contains at least one letter that appears twice in a row, like xx, abcdde (dd), or aabbccdd (aa, bb, cc, or dd).
-//     It does not contain the strings ab, cd, pq, or xy, even if they are part of one of the other requirements.
-
-// For example:
-
-//     ugknbfddgicrmopn is nice because it has at least three vowels (u...i...o...), a double letter (...dd...), and none of the disallowed substrings.
-//     aaa is nice because it has at least three vowels and a double letter, even though the letters used by different rules overlap.
-//     jchzalrnumimnmhp is naughty because it has no double letter.
-//     haegwjzuvuyypxyu is naughty because it contains the string xy.
-//     dvszwmarrgswjxmb is naughty because it contains only one vowel.
-
-// How many strings are nice?
-
-// --- Part Two ---
-
-// Realizing the error of his ways, Santa has switched to a better model of determining whether a string is naughty or nice.
-// None of the old rules apply, as they are all clearly ridiculous.
-
-// Now, a nice string is one with all of the following properties:
-
-//     It contains a pair of any two letters that appears at least twice in the string without overlapping, like xyxy (xy) or aabcdefgaa (aa), but not like aaa (aa, but it overlaps).
-//     It contains at least one letter which repeats with exactly one letter between them, like xyx, abcdefeghi (efe), or even aaa.
-
-// For example:
-
-//     qjhvhtzxzqqjkmpb is nice because is has a pair that appears twice (qj) and a letter that repeats with exactly one letter between them (zxz).
-//     xxyxx is nice because it has a pair that appears twice and a letter that repeats with one between, even though the letters used by each rule overlap.
-//     uurcxstgmygtbstg is naughty because it has a pair (tg) but no repeat with a single letter between them.
-//     ieodomkazucvgmuy is naughty because it has a repeating letter with one between (odo), but no pair that appears twice.
-
-// How many strings are nice under these new rules?
@@ -0,0 +1,477 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+use aoc_common::{parse::number, ParseError};
+
+use crate::explain::{Check, Evidence, Explanation};
+
+/// The rules for part one, as a spec.
+pub const PART_ONE: &str = "vowels>=3; double; forbid ab,cd,pq,xy";
+/// The rules for part two, as a spec.
+pub const PART_TWO: &str = "pair; sandwich";
+
+/// A property a word must have to be nice.
+///
+/// Rules display as their spec, so a rule set can be printed and parsed back.
+pub trait Rule: Debug + Display + Send + Sync {
+    fn matches(&self, word: &str) -> bool;
+
+    /// Whether the word matches, with what the rule found in it either way.
+    fn explain(&self, word: &str) -> Check;
+}
+
+fn check(rule: &dyn Rule, passed: bool, evidence: Option<Evidence>) -> Check {
+    Check {
+        rule: rule.to_string(),
+        passed,
+        evidence,
+    }
+}
+
+/// `vowels>=N`: at least `N` vowels, `aeiou` only.
+#[derive(Debug)]
+pub struct Vowels {
+    pub at_least: usize,
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+impl Rule for Vowels {
+    fn matches(&self, word: &str) -> bool {
+        word.chars().filter(|&c| is_vowel(c)).count() >= self.at_least
+    }
+
+    fn explain(&self, word: &str) -> Check {
+        let vowels: Vec<(usize, char)> = word
+            .chars()
+            .enumerate()
+            .filter(|&(_, c)| is_vowel(c))
+            .collect();
+        check(
+            self,
+            vowels.len() >= self.at_least,
+            Some(Evidence::Vowels(vowels)),
+        )
+    }
+}
+
+impl Display for Vowels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vowels>={}", self.at_least)
+    }
+}
+
+/// `double`: a letter that appears twice in a row, like `xx`.
+#[derive(Debug)]
+pub struct Double;
+
+impl Double {
+    fn find(word: &str) -> Option<Evidence> {
+        let chars: Vec<char> = word.chars().collect();
+        chars
+            .windows(2)
+            .position(|c| c[0] == c[1])
+            .map(|at| Evidence::Double {
+                letter: chars[at],
+                at,
+            })
+    }
+}
+
+impl Rule for Double {
+    fn matches(&self, word: &str) -> bool {
+        Double::find(word).is_some()
+    }
+
+    fn explain(&self, word: &str) -> Check {
+        let found = Double::find(word);
+        check(self, found.is_some(), found)
+    }
+}
+
+impl Display for Double {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "double")
+    }
+}
+
+/// `forbid ab,cd`: none of the strings anywhere in the word.
+#[derive(Debug)]
+pub struct Forbid {
+    pub strings: Vec<String>,
+}
+
+impl Rule for Forbid {
+    fn matches(&self, word: &str) -> bool {
+        !self.strings.iter().any(|s| word.contains(s.as_str()))
+    }
+
+    fn explain(&self, word: &str) -> Check {
+        let found = self
+            .strings
+            .iter()
+            .filter_map(|s| word.find(s.as_str()).map(|byte| (byte, s)))
+            .min()
+            .map(|(byte, s)| Evidence::Forbidden {
+                string: s.clone(),
+                at: word[..byte].chars().count(),
+            });
+        check(self, found.is_none(), found)
+    }
+}
+
+impl Display for Forbid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "forbid {}", self.strings.join(","))
+    }
+}
+
+/// `pair`: a pair of letters that appears twice without overlapping, like `xyxy` but
+/// not `aaa`.
+#[derive(Debug)]
+pub struct Pair;
+
+impl Pair {
+    fn find(word: &str) -> Option<Evidence> {
+        let chars: Vec<char> = word.chars().collect();
+        let mut first = HashMap::new();
+
+        chars.windows(2).enumerate().find_map(|(i, pair)| {
+            let j = *first.entry((pair[0], pair[1])).or_insert(i);
+            if i > j + 1 {
+                Some(Evidence::Pair {
+                    pair: pair.iter().collect(),
+                    first: j,
+                    second: i,
+                })
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl Rule for Pair {
+    fn matches(&self, word: &str) -> bool {
+        Pair::find(word).is_some()
+    }
+
+    fn explain(&self, word: &str) -> Check {
+        let found = Pair::find(word);
+        check(self, found.is_some(), found)
+    }
+}
+
+impl Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pair")
+    }
+}
+
+/// `sandwich`: a letter that repeats with exactly one letter between, like `xyx` or `aaa`.
+#[derive(Debug)]
+pub struct Sandwich;
+
+impl Sandwich {
+    fn find(word: &str) -> Option<Evidence> {
+        let chars: Vec<char> = word.chars().collect();
+        chars
+            .windows(3)
+            .enumerate()
+            .find(|(_, c)| c[0] == c[2])
+            .map(|(at, c)| Evidence::Sandwich {
+                letters: c.iter().collect(),
+                at,
+            })
+    }
+}
+
+impl Rule for Sandwich {
+    fn matches(&self, word: &str) -> bool {
+        Sandwich::find(word).is_some()
+    }
+
+    fn explain(&self, word: &str) -> Check {
+        let found = Sandwich::find(word);
+        check(self, found.is_some(), found)
+    }
+}
+
+impl Display for Sandwich {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sandwich")
+    }
+}
+
+/// `!rule`: the word must not match the rule.
+#[derive(Debug)]
+pub struct Not(pub Box<dyn Rule>);
+
+impl Rule for Not {
+    fn matches(&self, word: &str) -> bool {
+        !self.0.matches(word)
+    }
+
+    fn explain(&self, word: &str) -> Check {
+        let negated = self.0.explain(word);
+        check(self, !negated.passed, negated.evidence)
+    }
+}
+
+impl Display for Not {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "!{}", self.0)
+    }
+}
+
+/// Rules a word must all match to be nice.
+///
+/// A spec lists the rules separated by `;`, for example `vowels>=3; double; forbid ab,cd`:
+///
+/// - `vowels>=N`: at least `N` vowels
+/// - `double`: a letter twice in a row
+/// - `forbid S,T,...`: none of the strings
+/// - `pair`: a pair of letters twice, without overlapping
+/// - `sandwich`: a letter repeated with one letter between
+/// - `!rule`: anything but the rule
+#[derive(Debug)]
+pub struct RuleSet {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl RuleSet {
+    pub fn new(rules: Vec<Box<dyn Rule>>) -> RuleSet {
+        RuleSet { rules }
+    }
+
+    /// The rules from part one of the puzzle.
+    pub fn part_one() -> RuleSet {
+        RuleSet::new(vec![
+            Box::new(Vowels { at_least: 3 }),
+            Box::new(Double),
+            Box::new(Forbid {
+                strings: ["ab", "cd", "pq", "xy"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect(),
+            }),
+        ])
+    }
+
+    /// The rules from part two of the puzzle.
+    pub fn part_two() -> RuleSet {
+        RuleSet::new(vec![Box::new(Pair), Box::new(Sandwich)])
+    }
+
+    pub fn rules(&self) -> &[Box<dyn Rule>] {
+        &self.rules
+    }
+
+    /// Whether the word matches every rule; any word is nice under no rules.
+    pub fn is_nice(&self, word: &str) -> bool {
+        self.rules.iter().all(|rule| rule.matches(word))
+    }
+
+    /// Every rule's check for the word, nice or not.
+    pub fn explain(&self, word: &str) -> Explanation {
+        Explanation {
+            word: word.to_string(),
+            checks: self.rules.iter().map(|rule| rule.explain(word)).collect(),
+        }
+    }
+
+    /// Number of nice words in a list.
+    pub fn count_nice<'a>(&self, words: impl IntoIterator<Item = &'a str>) -> usize {
+        words.into_iter().filter(|word| self.is_nice(word)).count()
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = ParseError;
+
+    fn from_str(spec: &str) -> Result<RuleSet, ParseError> {
+        peg::parser! {
+            grammar rules_parser() for str {
+                rule _() = quiet!{[' ' | '\t']*}
+
+                rule count() -> usize = n:$(quiet!{['0'..='9']+} / expected!("a number")) {? number(n) }
+
+                rule string() -> String = s:$(quiet!{(![',' | ';' | ' ' | '\t'] [_])+} / expected!("a string")) {
+                    s.to_string()
+                }
+
+                rule rule_() -> Box<dyn Rule>
+                    = "!" _ negated:rule_() { Box::new(Not(negated)) }
+                    / "vowels" _ ">=" _ at_least:count() { Box::new(Vowels { at_least }) }
+                    / "double" { Box::new(Double) }
+                    / "forbid" [' ' | '\t']+ strings:(string() ++ (_ "," _)) { Box::new(Forbid { strings }) }
+                    / "pair" { Box::new(Pair) }
+                    / "sandwich" { Box::new(Sandwich) }
+
+                pub rule rules() -> Vec<Box<dyn Rule>> = _ rules:(rule_() ** (_ ";" _)) _ (";" _)? {
+                    rules
+                }
+            }
+        }
+
+        rules_parser::rules(spec)
+            .map(RuleSet::new)
+            .map_err(|e| ParseError::from_peg(spec, e))
+    }
+}
+
+/// The spec the rule set was parsed from, give or take whitespace.
+impl Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, rule) in self.rules.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", rule)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules() {
+        assert!(Vowels { at_least: 3 }.matches("xazegov"));
+        assert!(!Vowels { at_least: 3 }.matches("dvszwmarrgswjxmb"));
+        assert!(Double.matches("abcdde"));
+        assert!(!Double.matches("jchzalrnumimnmhp"));
+        assert!(!PART_ONE.parse::<RuleSet>().unwrap().rules()[2].matches("haegwjzuvuyypxyu"));
+        assert!(Pair.matches("aabcdefgaa"));
+        assert!(Pair.matches("aaaa"));
+        assert!(!Pair.matches("aaa"));
+        assert!(Sandwich.matches("abcdefeghi"));
+        assert!(!Sandwich.matches("uurcxstgmygtbstg"));
+        assert!(Not(Box::new(Double)).matches("abc"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let rules: RuleSet = PART_ONE.parse().unwrap();
+        assert_eq!(rules.to_string(), PART_ONE);
+        assert_eq!(rules.to_string(), RuleSet::part_one().to_string());
+        assert_eq!(
+            RuleSet::part_two().to_string(),
+            PART_TWO.parse::<RuleSet>().unwrap().to_string()
+        );
+
+        let rules: RuleSet = " vowels >= 2 ;! forbid x , yz;sandwich; ".parse().unwrap();
+        assert_eq!(rules.to_string(), "vowels>=2; !forbid x,yz; sandwich");
+        assert!(rules.is_nice("aexyx"));
+        assert!(!rules.is_nice("aeyay"));
+
+        assert_eq!("".parse::<RuleSet>().unwrap().rules().len(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = "double; vowels>=".parse::<RuleSet>().unwrap_err();
+        assert_eq!(
+            (error.column, error.expected),
+            (17, vec!["a number".to_string()])
+        );
+
+        let error = "double; triple".parse::<RuleSet>().unwrap_err();
+        assert_eq!(error.column, 9);
+        assert!(error.expected.contains(&"\"sandwich\"".to_string()));
+    }
+
+    #[test]
+    fn test_count_nice() {
+        let words = ["ugknbfddgicrmopn", "aaa", "jchzalrnumimnmhp", "xxyxx"];
+
+        assert_eq!(RuleSet::part_one().count_nice(words.iter().copied()), 2);
+        assert_eq!(RuleSet::part_two().count_nice(words.iter().copied()), 1);
+        assert_eq!(
+            "double; !forbid xy"
+                .parse::<RuleSet>()
+                .unwrap()
+                .count_nice(words.iter().copied()),
+            1
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = RuleSet::part_one().explain("haegwjzuvuyypxyu");
+        assert!(!explanation.is_nice());
+        assert_eq!(
+            explanation
+                .checks
+                .iter()
+                .map(|check| (check.passed, check.evidence.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    true,
+                    Some(Evidence::Vowels(vec![
+                        (1, 'a'),
+                        (2, 'e'),
+                        (7, 'u'),
+                        (9, 'u'),
+                        (15, 'u')
+                    ]))
+                ),
+                (
+                    true,
+                    Some(Evidence::Double {
+                        letter: 'y',
+                        at: 10
+                    })
+                ),
+                (
+                    false,
+                    Some(Evidence::Forbidden {
+                        string: "xy".to_string(),
+                        at: 13
+                    })
+                ),
+            ]
+        );
+        assert_eq!(
+            explanation
+                .failures()
+                .map(|c| c.rule.as_str())
+                .collect::<Vec<_>>(),
+            vec!["forbid ab,cd,pq,xy"]
+        );
+
+        let explanation = RuleSet::part_two().explain("qjhvhtzxzqqjkmpb");
+        assert!(explanation.is_nice());
+        assert_eq!(
+            explanation.checks[0].evidence,
+            Some(Evidence::Pair {
+                pair: "qj".to_string(),
+                first: 0,
+                second: 10
+            })
+        );
+        assert_eq!(
+            explanation.checks[1].evidence,
+            Some(Evidence::Sandwich {
+                letters: "hvh".to_string(),
+                at: 2
+            })
+        );
+
+        assert_eq!(Pair.explain("ieodomkazucvgmuy").evidence, None);
+        let check = Not(Box::new(Double)).explain("abba");
+        assert_eq!((check.rule.as_str(), check.passed), ("!double", false));
+        assert_eq!(
+            check.evidence,
+            Some(Evidence::Double { letter: 'b', at: 1 })
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day6;
+
+impl Generate for Day6 {
+    const DEFAULT_SIZE: usize = 300;
+
+    /// `size` is the number of instructions.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| {
+                let action = ["turn on", "turn off", "toggle"].choose(&mut rng).unwrap();
+                let (x1, x2) = (rng.gen_range(0..1000), rng.gen_range(0..1000));
+                let (y1, y2) = (rng.gen_range(0..1000), rng.gen_range(0..1000));
+
+                format!(
+                    "{} {},{} through {},{}\n",
+                    action,
+                    x1.min(x2),
+                    y1.min(y2),
+                    x1.max(x2),
+                    y1.max(y2)
+                )
+            })
+            .collect()
+    }
+}
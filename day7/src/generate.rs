@@ -0,0 +1,83 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day7;
+
+impl Generate for Day7 {
+    const DEFAULT_SIZE: usize = 339;
+
+    /// `size` is the number of wires. Every wire only reads wires defined before it, with
+    /// `b` given a signal first and `a` computed last, and then the lines are shuffled.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let size = size.max(2);
+
+        let wires: Vec<String> = (0..size)
+            .map(|i| match i {
+                0 => "b".to_string(),
+                _ if i == size - 1 => "a".to_string(),
+                _ => wire_name(i),
+            })
+            .collect();
+
+        let mut lines: Vec<String> = wires
+            .iter()
+            .enumerate()
+            .map(|(i, wire)| {
+                if i == 0 {
+                    return format!("{} -> {}\n", rng.gen::<u16>(), wire);
+                }
+
+                let defined = &wires[..i];
+                let source = defined.choose(&mut rng).unwrap();
+                let address = |rng: &mut StdRng| {
+                    if rng.gen_bool(0.8) {
+                        defined.choose(rng).unwrap().clone()
+                    } else {
+                        rng.gen::<u16>().to_string()
+                    }
+                };
+
+                match rng.gen_range(0..6) {
+                    0 => format!("{} -> {}\n", source, wire),
+                    1 => format!("{} AND {} -> {}\n", address(&mut rng), source, wire),
+                    2 => format!("{} OR {} -> {}\n", address(&mut rng), source, wire),
+                    3 => format!("{} LSHIFT {} -> {}\n", source, rng.gen_range(1..16), wire),
+                    4 => format!("{} RSHIFT {} -> {}\n", source, rng.gen_range(1..16), wire),
+                    _ => format!("NOT {} -> {}\n", source, wire),
+                }
+            })
+            .collect();
+
+        lines.shuffle(&mut rng);
+        lines.concat()
+    }
+}
+
+/// Two or more letters, so the names never clash with `a` and `b`.
+fn wire_name(i: usize) -> String {
+    let mut n = i + 26;
+    let mut name = Vec::new();
+
+    while n > 0 {
+        n -= 1;
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+
+    name.iter().rev().map(|&c| c as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wire_name() {
+        assert_eq!(wire_name(1), "aa");
+        assert_eq!(wire_name(26), "az");
+        assert_eq!(wire_name(27), "ba");
+        assert_eq!(wire_name(26 * 26), "zz");
+        assert_eq!(wire_name(26 * 26 + 1), "aaa");
+    }
+}
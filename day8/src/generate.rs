@@ -0,0 +1,33 @@
+use aoc_common::{generate::rng, Generate};
+use rand::Rng;
+
+use crate::Day8;
+
+impl Generate for Day8 {
+    const DEFAULT_SIZE: usize = 300;
+
+    /// `size` is the number of string literals, each mixing plain letters with `\\`, `\"`
+    /// and `\x` escapes.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+
+        (0..size)
+            .map(|_| {
+                let length = rng.gen_range(0..30);
+                let mut line = String::from("\"");
+
+                for _ in 0..length {
+                    match rng.gen_range(0..10) {
+                        0 => line.push_str("\\\\"),
+                        1 => line.push_str("\\\""),
+                        2 => line.push_str(&format!("\\x{:02x}", rng.gen::<u8>())),
+                        _ => line.push(rng.gen_range('a'..='z')),
+                    }
+                }
+
+                line.push_str("\"\n");
+                line
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,234 @@
+use anyhow::bail;
+use aoc_common::{input::lines, Solution};
+
+pub mod generate;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = u32;
+    type PartTwo = usize;
+    type Options = ();
+
+    fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+        Ok(lines(input).map(|(_, line)| line).collect())
+    }
+
+    fn part_one(input: &Vec<&str>, _: &()) -> anyhow::Result<u32> {
+        let count = input
+            .iter()
+            .map(|line| count_characters(line))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+
+        Ok(count.0 - count.1)
+    }
+
+    fn part_two(input: &Vec<&str>, _: &()) -> anyhow::Result<usize> {
+        let escape_count = input
+            .iter()
+            .map(|line| escape_characters(line))
+            .fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+
+        Ok(escape_count.1 - escape_count.0)
+    }
+}
+
+/// Characters of code and characters in memory for a double-quoted string literal.
+pub fn count_characters(input: &str) -> anyhow::Result<(u32, u32)> {
+    let mut chars = match input.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(contents) => contents.chars(),
+        None => bail!("Expected a quoted string literal: {:?}", input),
+    };
+
+    let mut a = 2;
+    let mut b = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                a += 1;
+
+                match chars.next() {
+                    Some('"') | Some('\\') => {
+                        a += 1;
+                        b += 1;
+                    }
+                    Some('x') => {
+                        let digits = [chars.next(), chars.next()];
+                        if !digits
+                            .iter()
+                            .all(|d| matches!(d, Some(d) if d.is_ascii_hexdigit()))
+                        {
+                            bail!("Expected two hex digits after \\x: {:?}", input);
+                        }
+                        a += 3;
+                        b += 1;
+                    }
+                    _ => bail!("Unexpected escape: {:?}", input),
+                }
+            }
+            '"' => bail!("Unescaped quote inside the literal: {:?}", input),
+            _ => {
+                a += 1;
+                b += 1;
+            }
+        }
+    }
+
+    return Ok((a, b));
+}
+
+pub fn escape_characters(input: &str) -> (usize, usize) {
+    let chars = input.chars();
+
+    let mut a = 0;
+
+    for c in chars {
+        match c {
+            '"' | '\\' => { 
+              a += 1;
+            },
+            _ => {}
+        }
+    }
+
+    return (input.len(), input.len() + a + 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Writes `string` as a literal, escaping quotes and backslashes the way part two does.
+    fn encode(string: &str) -> String {
+        format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    /// One character in memory, and how it may be written in a literal.
+    fn character() -> impl Strategy<Value = String> {
+        prop_oneof![
+            "[a-z]",
+            Just("\\\\".to_string()),
+            Just("\\\"".to_string()),
+            any::<u8>().prop_map(|byte| format!("\\x{:02x}", byte)),
+        ]
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count_characters("\"\"").unwrap(), (2, 0));
+        assert_eq!(count_characters("\"\\\"\"").unwrap(), (4, 1));
+        assert_eq!(count_characters("\"abcd\"").unwrap(), (6, 4));
+        assert_eq!(count_characters("\"\\x23\"").unwrap(), (6, 1));
+    }
+
+    #[test]
+    fn test_count_rejects_bad_literals() {
+        let inputs = [
+            "",
+            "\"",
+            "abc",
+            "\"abc",
+            "\"a\"b\"",
+            "\"\\\"",
+            "\"\\q\"",
+            "\"\\x2\"",
+            "\"\\xzz\"",
+        ];
+
+        for input in inputs.iter() {
+            assert!(count_characters(input).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_skips_blank_lines() {
+        let input = Day8::parse("\"abc\"\n\n\"\\x27\"\n\n").unwrap();
+
+        assert_eq!(input, vec!["\"abc\"", "\"\\x27\""]);
+        assert_eq!(Day8::part_one(&input, &()).unwrap(), 2 + 5);
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_characters("\"\""), (2, 6));
+        assert_eq!(escape_characters("\"abc\""), (5, 9));
+        assert_eq!(escape_characters("\"aaa\\\"aaa\""), (10, 16));
+        assert_eq!(escape_characters("\"\\x27\""), (6, 11));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_count_characters(characters in prop::collection::vec(character(), 0..40)) {
+            let literal = format!("\"{}\"", characters.concat());
+
+            prop_assert_eq!(
+                count_characters(&literal).unwrap(),
+                (literal.len() as u32, characters.len() as u32)
+            );
+        }
+
+        #[test]
+        fn prop_escape_round_trips(characters in prop::collection::vec(character(), 0..40)) {
+            let literal = format!("\"{}\"", characters.concat());
+            let encoded = encode(&literal);
+
+            prop_assert_eq!(escape_characters(&literal), (literal.len(), encoded.len()));
+            prop_assert_eq!(
+                count_characters(&encoded).unwrap(),
+                (encoded.len() as u32, literal.len() as u32)
+            );
+        }
+    }
+}
+
+// --- Day 8: Matchsticks ---
+
+// Space on the sleigh is limited this year, and so Santa will be bringing his list as a digital copy.
+// He needs to know how much space it will take up when stored.
+
+// It is common in many programming languages to provide a way to escape special characters in strings.
+// For example, C, JavaScript, Perl, Python, and even PHP handle special characters in very similar ways.
+
+// However, it is important to realize the difference between the number of characters in the code
+// representation of the string literal and the number of characters in the in-memory string itself.
+
+// For example:
+
+//     "" is 2 characters of code (the two double quotes), but the string contains zero characters.
+//     "abc" is 5 characters of code, but 3 characters in the string data.
+//     "aaa\"aaa" is 10 characters of code, but the string itself contains six "a" characters and a single,
+//         escaped quote character, for a total of 7 characters in the string data.
+//     "\x27" is 6 characters of code, but the string itself contains just one - an apostrophe ('), escaped using hexadecimal notation.
+
+// Santa's list is a file that contains many double-quoted string literals, one on each line.
+// The only escape sequences used are \\ (which represents a single backslash),
+// \" (which represents a lone double-quote character), and \x plus two hexadecimal characters (which represents a single character with that ASCII code).
+
+// Disregarding the whitespace in the file, what is the number of characters of code for string
+// literals minus the number of characters in memory for the values of the strings in total for the entire file?
+
+// For example, given the four strings above, the total number of characters of string code (2 + 5 + 10 + 6 = 23)
+// minus the total number of characters in memory for string values (0 + 3 + 7 + 1 = 11) is 23 - 11 = 12.
+
+// --- Part Two ---
+
+// Now, let's go the other way. In addition to finding the number of characters of code, you should now encode each code
+// representation as a new string and find the number of characters of the new encoded representation,
+// including the surrounding double quotes.
+
+// For example:
+
+//     "" encodes to "\"\"", an increase from 2 characters to 6.
+//     "abc" encodes to "\"abc\"", an increase from 5 characters to 9.
+//     "aaa\"aaa" encodes to "\"aaa\\\"aaa\"", an increase from 10 characters to 16.
+//     "\x27" encodes to "\"\\x27\"", an increase from 6 characters to 11.
+
+// Your task is to find the total number of characters to represent the newly encoded strings minus the number of characters of code in each original string literal. For example, for the strings above, the total encoded length (6 + 9 + 16 + 11 = 42) minus the characters in the original code representation (23, just like in the first part of this puzzle) is 42 - 23 = 19.
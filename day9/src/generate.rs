@@ -0,0 +1,47 @@
+use aoc_common::{generate::rng, Generate};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day9;
+
+impl Generate for Day9 {
+    const DEFAULT_SIZE: usize = 8;
+
+    /// `size` is the number of cities, with a distance between every pair of them. The
+    /// solver tries every route, so keep it small.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = rng(seed);
+        let mut cities: Vec<String> = Vec::new();
+
+        while cities.len() < size {
+            let city = city_name(&mut rng);
+            if !cities.contains(&city) {
+                cities.push(city);
+            }
+        }
+
+        let mut lines = String::new();
+        for (i, from) in cities.iter().enumerate() {
+            for to in &cities[i + 1..] {
+                lines.push_str(&format!(
+                    "{} to {} = {}\n",
+                    from,
+                    to,
+                    rng.gen_range(1..=150)
+                ));
+            }
+        }
+
+        lines
+    }
+}
+
+fn city_name(rng: &mut StdRng) -> String {
+    let length = rng.gen_range(4..=10);
+
+    (0..length)
+        .map(|i| match i {
+            0 => rng.gen_range('A'..='Z'),
+            _ => rng.gen_range('a'..='z'),
+        })
+        .collect()
+}